@@ -0,0 +1,27 @@
+use nu_ansi_term::Style as NuStyle;
+
+use crate::style::Style;
+
+pub const RESET: &str = "\x1b[0m";
+
+/// Returns the escape code that turns on `style`, without the trailing reset.
+pub fn ansi_color_code_without_reset(style: Style) -> String {
+    let nu_style = NuStyle::from(style);
+    let styled_str = format!("{}", nu_style.paint(""));
+
+    styled_str.replace(RESET, "")
+}
+
+/// Paints `text` with the escape code `color`, re-applying it after every reset found inside `text`.
+///
+/// This allows already highlighted spans inside `text` (for example the output of a nested highlighter) to keep their
+/// own style while everything around them is painted with `color`.
+pub fn paint_around_highlights(color: &str, text: &str) -> String {
+    if color.is_empty() {
+        return text.to_string();
+    }
+
+    let recolored = text.replace(RESET, &format!("{}{}", RESET, color));
+
+    format!("{}{}{}", color, recolored, RESET)
+}
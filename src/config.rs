@@ -1,26 +1,165 @@
-use crate::Style;
+use crate::{Highlighter, Style};
 
 pub struct NumberConfig {
     pub style: Style,
+    /// Includes a leading `-` or `+` in the number, such as in `-3`. Signs glued to a word, like in `x-3`, are skipped.
+    pub signs: bool,
+    /// Matches scientific notation such as `1e-9` and `6.02E23`.
+    pub scientific: bool,
+    /// Matches hexadecimal, octal and binary numbers such as `0xff`, `0o755` and `0b1010`.
+    pub radix_prefixes: bool,
+    /// Matches digit separators such as `1_000_000` and `1,000,000`. Commas are only matched between groups of three
+    /// digits.
+    pub separators: bool,
+    /// Style of the sign. Falls back to `style`.
+    pub sign: Option<Style>,
+    /// Style of the exponent, including the `e`. Falls back to `style`.
+    pub exponent: Option<Style>,
+    /// Style of the radix prefix, such as `0x`. Falls back to `style`.
+    pub radix_prefix: Option<Style>,
+}
+
+pub struct QuantityConfig {
+    pub magnitude: Style,
+    pub unit: Style,
+    /// Units that may follow a number, such as `ms`, `GiB` or `%`.
+    ///
+    /// Numbers may be separated from units longer than one character by a space, like in `512 KB`, while single
+    /// character units must directly follow the number. Durations may chain several units, like in `2h30m`.
+    pub units: Vec<String>,
+}
+
+pub struct DurationConfig {
+    pub number: Style,
+    pub unit: Style,
+    pub separator: Style,
+    /// Words that mark `hh:mm:ss` as an elapsed time rather than a time of day, such as `took` in `took 00:05:12.300`.
+    /// Elapsed times with days or with more than 23 hours, such as `1-02:03:04` and `36:00:00`, are always matched.
+    pub elapsed_keywords: Vec<String>,
+}
+
+pub struct ThresholdConfig {
+    /// Keys whose values are styled, such as `latency` in `latency=1200ms` or `latency: 20 ms`.
+    pub keys: Vec<String>,
+    /// Regexes whose `value` capture group is styled, such as `took (?P<value>\S+)`.
+    pub regexes: Vec<String>,
+    /// Factors for normalizing values with a unit, such as `("s", 1000.0)` and `("ms", 1.0)` to compare durations in
+    /// milliseconds. Values without a unit are taken as is, while values with an unknown unit are left untouched.
+    pub units: Vec<(String, f64)>,
+    pub scale: ThresholdScale,
+}
+
+#[derive(Clone)]
+pub enum ThresholdScale {
+    /// Picks the style of the highest threshold the normalized value reaches. The thresholds must be in ascending
+    /// order, and values below the first threshold are left untouched.
+    Steps(Vec<(f64, Style)>),
+    /// Blends the foreground color from `from` at `low` to `to` at `high`. Values outside the range get the color of the
+    /// nearest end.
+    Gradient {
+        low: f64,
+        high: f64,
+        from: (u8, u8, u8),
+        to: (u8, u8, u8),
+    },
 }
 
 pub struct UuidConfig {
     pub number: Style,
     pub letter: Style,
     pub dash: Style,
+    /// Styles each UUID as a whole with a style picked by its hash, so that the same UUID always gets the same style.
+    /// Ignored if empty.
+    pub palette: Vec<Style>,
 }
 
 pub struct KeyValueConfig {
     pub key: Style,
     pub separator: Style,
+    /// Separators between keys and values, such as `=`, `:` or `=>`.
+    ///
+    /// Keys followed by a separator ending in `:` are only matched when the separator is followed by whitespace, so
+    /// that times, URLs and IPv6 addresses are left to their own highlighters. Falls back to `=` if empty.
+    pub separators: Vec<String>,
+    pub value: Style,
+    pub quote_token: Style,
+    /// Parses full logfmt pairs such as `key=value` and `key="quoted value"`, styling values and quotes as well.
+    pub logfmt: bool,
+    /// Highlighter applied to values when in logfmt mode.
+    pub inner: Option<Highlighter>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct DateTimeConfig {
     pub date: Style,
     pub time: Style,
     pub zone: Style,
     pub separator: Style,
+    /// Names of months, weekdays and zones. The timestamp normalizer and the relative time annotations use the same
+    /// names.
+    pub names: TimestampNames,
+}
+
+#[derive(Clone)]
+pub struct TimestampNames {
+    /// Zone abbreviations styled with `zone` when they follow a time, such as `UTC` in `10:00:00 UTC`. Numeric offsets
+    /// and region names in brackets, such as `+02:00` and `[Europe/Oslo]`, are always styled.
+    pub zones: Vec<String>,
+    /// Names of the months, starting with January, for textual dates such as `Jan  5`, `05 Mar 2024` and
+    /// `05/Mar/2024`. Each month may have several names, such as `Sep` and `September`, and names from several
+    /// languages.
+    pub months: Vec<Vec<String>>,
+    /// Names of the weekdays that may precede textual dates, such as `Tue` in `Tue, 05 Mar 2024`.
+    pub weekdays: Vec<String>,
+}
+
+/// Format and zone that timestamps are rewritten into, such as ISO-8601 in UTC or local time.
+///
+/// Timestamps with a date, a time and an optional zone are rewritten, such as `2024-03-05 10:00:00+02:00`,
+/// `Tue, 05 Mar 2024 10:00:00 GMT` and `05/Mar/2024:10:00:00 +0000`. Timestamps with a zone abbreviation other than
+/// `UTC` or `GMT`, and syslog timestamps, which lack a year, are left as is.
+#[derive(Clone)]
+pub struct TimestampFormat {
+    /// Format in the syntax of `strftime`, such as `%Y-%m-%dT%H:%M:%S%.3fZ`. Timestamps are left as is if the format is
+    /// invalid.
+    pub format: String,
+    /// Zone the timestamps are converted to.
+    pub zone: TimestampZone,
+    /// Zone of timestamps without an offset or zone, such as `2024-03-05 10:00:00`.
+    pub assumed_zone: TimestampZone,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampZone {
+    Local,
+    Utc,
+    /// Offset in seconds east of UTC, such as `7200` for `+02:00`.
+    Fixed(i32),
+}
+
+pub struct EpochConfig {
+    /// Keys whose values may be Unix timestamps, such as `ts` in `ts=1717171717` or `"created": 1717171717123`.
+    ///
+    /// Values in seconds, milliseconds, microseconds and nanoseconds are recognized by their magnitude, and only if they
+    /// fall between the years 2000 and 2100.
+    pub keys: Vec<String>,
+    pub style: Style,
+    /// Renders the timestamp as an ISO-8601 date in UTC, such as `2024-05-31T16:08:37Z`. Left as is if `None`.
+    pub render: Option<EpochRender>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochRender {
+    /// Keeps the timestamp and appends the rendered date in parentheses, such as `1717171717 (2024-05-31T16:08:37Z)`.
+    Append,
+    /// Replaces the timestamp with the rendered date.
+    Replace,
+}
+
+pub struct RelativeTimeConfig {
+    /// Styles the time since the previous timestamp by its number of seconds, such as `5.0` for `+5.000s` and
+    /// `-5.000s`.
+    pub scale: ThresholdScale,
 }
 
 pub struct IpV4Config {
@@ -70,11 +209,96 @@ pub struct JsonConfig {
     pub square_bracket: Style,
     pub comma: Style,
     pub colon: Style,
+    pub string: Style,
+    pub number: Style,
+    pub boolean: Style,
+    pub null: Style,
+    /// Rules styling values by their path in the document. The first matching rule is applied.
+    pub path_rules: Vec<JsonPathRule>,
+    /// Expands JSON documents into indented, multi-line output.
+    pub pretty: Option<JsonPrettyConfig>,
+    /// Highlights string values that themselves contain JSON.
+    pub nested_json: Option<NestedJsonMode>,
+    /// Highlighter applied to the content of string values.
+    pub inner: Option<Highlighter>,
+}
+
+#[derive(Clone, Copy)]
+pub struct JsonPrettyConfig {
+    /// Number of spaces per nesting level.
+    pub indent: usize,
+    /// Objects and arrays nested deeper than this are collapsed to `{…}` and `[…]`.
+    pub max_depth: Option<usize>,
+    /// Arrays with more elements than this are cut off with `…`.
+    pub max_array_length: Option<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NestedJsonMode {
+    /// Highlights the nested JSON as it appears in the string, escaped quotes included. Strings whose escaping
+    /// can't be reproduced exactly are left as is.
+    InPlace,
+    /// Replaces the string content with the unescaped JSON document.
+    Unescaped,
+}
+
+pub struct JsonPathRule {
+    /// Path to the values, such as `level`, `request.status` or `$..id` for an `id` key at any depth.
+    pub path: String,
+    pub style: JsonValueStyle,
+}
+
+pub enum JsonValueStyle {
+    /// Styles all matching values the same.
+    Fixed(Style),
+    /// Picks the style by the value, compared case-insensitively and without quotes. Other values keep their style.
+    ByValue(Vec<(String, Style)>),
+    /// Replaces the value with asterisks.
+    Mask(Style),
+    /// Picks the style from the palette by a hash of the value, so that equal values always get the same style.
+    Hashed(Vec<Style>),
+}
+
+/// Styles for struct dumps, such as Rust's `Debug` output and Go's `%+v` verb.
+pub struct StructConfig {
+    pub type_name: Style,
+    pub field: Style,
+    pub separator: Style,
+    pub bracket: Style,
+    pub comma: Style,
+    pub quote_token: Style,
+    pub string: Style,
+    pub number: Style,
+    pub boolean: Style,
+    /// Style of `None`, `nil` and `<nil>`.
+    pub null: Style,
+}
+
+pub struct XmlConfig {
+    pub tag: Style,
+    pub attribute: Style,
+    pub equals: Style,
+    pub attribute_value: Style,
+    /// Style of `<`, `</`, `>`, `/>` and the other delimiters of tags.
+    pub angle_bracket: Style,
+    pub cdata: Style,
+    pub comment: Style,
 }
 
 pub struct QuotesConfig {
     pub quotes_token: char,
     pub style: Style,
+    /// Highlighter applied to the content between the quotes.
+    pub inner: Option<Highlighter>,
+}
+
+pub struct LogLevelConfig {
+    pub trace: Style,
+    pub debug: Style,
+    pub info: Style,
+    pub warn: Style,
+    pub error: Style,
+    pub fatal: Style,
 }
 
 #[derive(PartialEq, Eq, Ord, PartialOrd, Debug, Clone)]
@@ -88,3 +312,10 @@ pub struct RegexConfig {
     pub regex: String,
     pub style: Style,
 }
+
+/// Like [`RegexConfig`], but picks the style of each match from the palette by a hash of the matched text.
+#[derive(PartialEq, Eq, Ord, PartialOrd, Debug, Clone)]
+pub struct HashedRegexConfig {
+    pub regex: String,
+    pub palette: Vec<Style>,
+}
@@ -1,12 +1,72 @@
 use crate::{
-    Color, DateTimeConfig, IpV4Config, IpV6Config, JsonConfig, KeyValueConfig, NumberConfig, PointerConfig,
-    QuotesConfig, Style, UnixPathConfig, UnixProcessConfig, UrlConfig, UuidConfig,
+    Color, DateTimeConfig, DurationConfig, EpochConfig, IpV4Config, IpV6Config, JsonConfig, JsonPrettyConfig,
+    KeyValueConfig, LogLevelConfig, NumberConfig, PointerConfig, QuantityConfig, QuotesConfig, RelativeTimeConfig,
+    StructConfig, Style, ThresholdConfig, ThresholdScale, TimestampFormat, TimestampNames, TimestampZone,
+    UnixPathConfig, UnixProcessConfig, UrlConfig, UuidConfig, XmlConfig,
 };
 
 impl Default for NumberConfig {
     fn default() -> Self {
         NumberConfig {
             style: Style::new().fg(Color::Cyan),
+            signs: false,
+            scientific: false,
+            radix_prefixes: false,
+            separators: false,
+            sign: None,
+            exponent: None,
+            radix_prefix: None,
+        }
+    }
+}
+
+impl Default for QuantityConfig {
+    fn default() -> Self {
+        let units = [
+            "ns", "us", "µs", "ms", "s", "m", "min", "h", "d", "B", "kB", "KB", "MB", "GB", "TB", "PB", "KiB", "MiB",
+            "GiB", "TiB", "PiB", "%",
+        ];
+
+        QuantityConfig {
+            magnitude: Style::new().fg(Color::Cyan),
+            unit: Style::new().fg(Color::Cyan).faint(),
+            units: units.iter().map(|unit| unit.to_string()).collect(),
+        }
+    }
+}
+
+impl Default for DurationConfig {
+    fn default() -> Self {
+        let elapsed_keywords = ["took", "elapsed", "duration", "uptime", "runtime"];
+
+        DurationConfig {
+            number: Style::new().fg(Color::Cyan),
+            unit: Style::new().fg(Color::Cyan).faint(),
+            separator: Style::new().faint(),
+            elapsed_keywords: elapsed_keywords.iter().map(|keyword| keyword.to_string()).collect(),
+        }
+    }
+}
+
+impl Default for ThresholdConfig {
+    fn default() -> Self {
+        let units = [
+            ("ns", 0.000_001),
+            ("us", 0.001),
+            ("µs", 0.001),
+            ("ms", 1.0),
+            ("s", 1000.0),
+        ];
+
+        ThresholdConfig {
+            keys: Vec::new(),
+            regexes: Vec::new(),
+            units: units.iter().map(|(unit, factor)| (unit.to_string(), *factor)).collect(),
+            scale: ThresholdScale::Steps(vec![
+                (0.0, Style::new().fg(Color::Green)),
+                (100.0, Style::new().fg(Color::Yellow)),
+                (1000.0, Style::new().fg(Color::Red)),
+            ]),
         }
     }
 }
@@ -17,6 +77,7 @@ impl Default for UuidConfig {
             number: Style::new().fg(Color::Blue).italic(),
             letter: Style::new().fg(Color::Magenta).italic(),
             dash: Style::new().fg(Color::Red),
+            palette: Vec::new(),
         }
     }
 }
@@ -26,6 +87,61 @@ impl Default for KeyValueConfig {
         KeyValueConfig {
             key: Style::new().faint(),
             separator: Style::new().fg(Color::White),
+            separators: vec!["=".to_string()],
+            value: Style::new(),
+            quote_token: Style::new().fg(Color::Yellow).faint(),
+            logfmt: false,
+            inner: None,
+        }
+    }
+}
+
+impl Default for TimestampNames {
+    fn default() -> Self {
+        let zones = [
+            "UTC", "GMT", "WET", "WEST", "CET", "CEST", "EET", "EEST", "MSK", "BST", "IST", "JST", "KST", "AEST",
+            "AEDT", "ACST", "AWST", "NZST", "NZDT", "EST", "EDT", "CST", "CDT", "MST", "MDT", "PST", "PDT", "AKST",
+            "AKDT", "HST",
+        ];
+
+        let months: [&[&str]; 12] = [
+            &["Jan", "January"],
+            &["Feb", "February"],
+            &["Mar", "March"],
+            &["Apr", "April"],
+            &["May"],
+            &["Jun", "June"],
+            &["Jul", "July"],
+            &["Aug", "August"],
+            &["Sep", "Sept", "September"],
+            &["Oct", "October"],
+            &["Nov", "November"],
+            &["Dec", "December"],
+        ];
+        let weekdays = [
+            "Mon",
+            "Tue",
+            "Wed",
+            "Thu",
+            "Fri",
+            "Sat",
+            "Sun",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ];
+
+        TimestampNames {
+            zones: zones.iter().map(|zone| zone.to_string()).collect(),
+            months: months
+                .iter()
+                .map(|names| names.iter().map(|name| name.to_string()).collect())
+                .collect(),
+            weekdays: weekdays.iter().map(|weekday| weekday.to_string()).collect(),
         }
     }
 }
@@ -37,6 +153,42 @@ impl Default for DateTimeConfig {
             time: Style::new().fg(Color::Blue),
             zone: Style::new().fg(Color::Red),
             separator: Style::new().faint(),
+            names: TimestampNames::default(),
+        }
+    }
+}
+
+impl Default for TimestampFormat {
+    fn default() -> Self {
+        TimestampFormat {
+            format: "%Y-%m-%dT%H:%M:%S%.3fZ".to_string(),
+            zone: TimestampZone::Utc,
+            assumed_zone: TimestampZone::Utc,
+        }
+    }
+}
+
+impl Default for EpochConfig {
+    fn default() -> Self {
+        EpochConfig {
+            keys: ["ts", "time", "timestamp", "epoch"]
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+            style: Style::new().fg(Color::Magenta),
+            render: None,
+        }
+    }
+}
+
+impl Default for RelativeTimeConfig {
+    fn default() -> Self {
+        RelativeTimeConfig {
+            scale: ThresholdScale::Steps(vec![
+                (0.0, Style::new().faint()),
+                (1.0, Style::new().fg(Color::Yellow)),
+                (10.0, Style::new().fg(Color::Red)),
+            ]),
         }
     }
 }
@@ -114,6 +266,45 @@ impl Default for JsonConfig {
             square_bracket: Style::new().faint(),
             comma: Style::new().faint(),
             colon: Style::new().faint(),
+            string: Style::new(),
+            number: Style::new().fg(Color::Cyan),
+            boolean: Style::new().fg(Color::Magenta),
+            null: Style::new().fg(Color::Red).italic(),
+            path_rules: Vec::new(),
+            pretty: None,
+            nested_json: None,
+            inner: None,
+        }
+    }
+}
+
+impl Default for StructConfig {
+    fn default() -> Self {
+        StructConfig {
+            type_name: Style::new().fg(Color::Blue),
+            field: Style::new().fg(Color::Yellow),
+            separator: Style::new().faint(),
+            bracket: Style::new().faint(),
+            comma: Style::new().faint(),
+            quote_token: Style::new().fg(Color::Yellow).faint(),
+            string: Style::new(),
+            number: Style::new().fg(Color::Cyan),
+            boolean: Style::new().fg(Color::Magenta),
+            null: Style::new().fg(Color::Red).italic(),
+        }
+    }
+}
+
+impl Default for XmlConfig {
+    fn default() -> Self {
+        XmlConfig {
+            tag: Style::new().fg(Color::Blue),
+            attribute: Style::new().fg(Color::Yellow),
+            equals: Style::new().faint(),
+            attribute_value: Style::new().fg(Color::Green),
+            angle_bracket: Style::new().faint(),
+            cdata: Style::new().fg(Color::Cyan),
+            comment: Style::new().faint().italic(),
         }
     }
 }
@@ -123,6 +314,30 @@ impl Default for QuotesConfig {
         QuotesConfig {
             quotes_token: '"',
             style: Style::new().fg(Color::Yellow),
+            inner: None,
+        }
+    }
+}
+
+impl Default for LogLevelConfig {
+    fn default() -> Self {
+        LogLevelConfig {
+            trace: Style::new().faint(),
+            debug: Style::new().fg(Color::Magenta),
+            info: Style::new().fg(Color::White),
+            warn: Style::new().fg(Color::Yellow),
+            error: Style::new().fg(Color::Red),
+            fatal: Style::new().fg(Color::Red).bold(),
+        }
+    }
+}
+
+impl Default for JsonPrettyConfig {
+    fn default() -> Self {
+        JsonPrettyConfig {
+            indent: 2,
+            max_depth: None,
+            max_array_length: None,
         }
     }
 }
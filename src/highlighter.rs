@@ -1,20 +1,31 @@
 use crate::config::*;
 use crate::error::Error;
 use crate::highlighters::date_dash::DateDashHighlighter;
+use crate::highlighters::date_normalize::TimestampNormalizer;
+use crate::highlighters::date_text::TextualDateHighlighter;
 use crate::highlighters::date_time::TimeHighlighter;
+use crate::highlighters::duration::DurationHighlighter;
+use crate::highlighters::epoch::EpochHighlighter;
 use crate::highlighters::ip_v4::IpV4Highlighter;
 use crate::highlighters::ip_v6::IpV6Highlighter;
-use crate::highlighters::json::JsonHighlighter;
+use crate::highlighters::json::{JsonDocument, JsonHighlighter};
 use crate::highlighters::key_value::KeyValueHighlighter;
 use crate::highlighters::keyword::KeywordHighlighter;
+use crate::highlighters::line::LineHighlighter;
+use crate::highlighters::log_level::LogLevelHighlighter;
 use crate::highlighters::number::NumberHighlighter;
 use crate::highlighters::pointer::PointerHighlighter;
+use crate::highlighters::quantity::QuantityHighlighter;
 use crate::highlighters::quote::QuoteHighlighter;
 use crate::highlighters::regex::RegexpHighlighter;
+use crate::highlighters::relative_time::{RelativeTime, Timestamp};
+use crate::highlighters::struct_dump::StructHighlighter;
+use crate::highlighters::threshold::ThresholdHighlighter;
 use crate::highlighters::unix_path::UnixPathHighlighter;
 use crate::highlighters::unix_process::UnixProcessHighlighter;
 use crate::highlighters::url::UrlHighlighter;
 use crate::highlighters::uuid::UuidHighlighter;
+use crate::highlighters::xml::XmlHighlighter;
 use crate::normalizer::normalize_keyword_configs;
 use crate::split_and_apply::apply_only_to_unhighlighted;
 use std::sync::Arc;
@@ -23,20 +34,32 @@ pub trait Highlight: Sync + Send {
     fn apply(&self, input: &str) -> String;
 }
 
+#[derive(Clone)]
 pub struct Highlighter {
     highlighters: Vec<Arc<dyn Highlight>>,
+    line_highlighters: Vec<Arc<LineHighlighter>>,
+    json_highlighter: Option<Arc<JsonHighlighter>>,
+    relative_time: Option<Arc<RelativeTime>>,
 }
 
 impl Highlighter {
     const fn new() -> Self {
         Highlighter {
             highlighters: Vec::new(),
+            line_highlighters: Vec::new(),
+            json_highlighter: None,
+            relative_time: None,
         }
     }
 
     pub fn builder() -> HighlightBuilder {
         HighlightBuilder {
             highlighters: Vec::new(),
+            line_highlighters: Vec::new(),
+            json_highlighter: None,
+            timestamp_names: TimestampNames::default(),
+            timestamp_normalizer: None,
+            relative_time: None,
             regex_errors: Vec::new(),
         }
     }
@@ -47,10 +70,90 @@ impl Highlighter {
         self
     }
 
+    fn with_line_highlighters(mut self, line_highlighters: Vec<Arc<LineHighlighter>>) -> Self {
+        self.line_highlighters = line_highlighters;
+
+        self
+    }
+
+    fn with_json_highlighter(mut self, json_highlighter: Option<Arc<JsonHighlighter>>) -> Self {
+        self.json_highlighter = json_highlighter;
+
+        self
+    }
+
+    fn with_relative_time(mut self, relative_time: Option<Arc<RelativeTime>>) -> Self {
+        self.relative_time = relative_time;
+
+        self
+    }
+
     pub fn apply(&self, input: &str) -> String {
-        self.highlighters.iter().fold(input.to_owned(), |acc, highlighter| {
+        self.highlight(input, input.to_owned())
+    }
+
+    /// Returns a stream for highlighting consecutive lines, such as the lines of a log file.
+    ///
+    /// Unlike [`Highlighter::apply`], the stream keeps state between lines, which allows it to highlight JSON
+    /// documents that are spread over several lines and to annotate lines with the time since the previous timestamp.
+    pub const fn stream(&self) -> LineStream<'_> {
+        LineStream::new(self)
+    }
+
+    /// Highlights `prepared`, which is `line` with some parts possibly highlighted already.
+    fn highlight(&self, line: &str, prepared: String) -> String {
+        let highlighted = self.highlighters.iter().fold(prepared, |acc, highlighter| {
             apply_only_to_unhighlighted(&acc, highlighter)
-        })
+        });
+
+        // Only the first matching line highlighter is applied, so that line styles never stack
+        match self
+            .line_highlighters
+            .iter()
+            .find(|line_highlighter| line_highlighter.matches(line))
+        {
+            Some(line_highlighter) => line_highlighter.apply(&highlighted),
+            None => highlighted,
+        }
+    }
+}
+
+/// Highlights consecutive lines, keeping track of JSON documents that span several lines and of the previous
+/// timestamp.
+pub struct LineStream<'a> {
+    highlighter: &'a Highlighter,
+    json_document: Option<JsonDocument>,
+    previous_timestamp: Option<Timestamp>,
+}
+
+impl<'a> LineStream<'a> {
+    const fn new(highlighter: &'a Highlighter) -> Self {
+        LineStream {
+            highlighter,
+            json_document: None,
+            previous_timestamp: None,
+        }
+    }
+
+    /// Highlights the next line of the stream.
+    pub fn apply(&mut self, line: &str) -> String {
+        let prepared = match &self.highlighter.json_highlighter {
+            Some(json_highlighter) => json_highlighter.apply_to_line(line, &mut self.json_document),
+            None => line.to_owned(),
+        };
+
+        let highlighted = self.highlighter.highlight(line, prepared);
+
+        let annotation = self
+            .highlighter
+            .relative_time
+            .as_ref()
+            .and_then(|relative_time| relative_time.annotate(line, &mut self.previous_timestamp));
+
+        match annotation {
+            Some(annotation) => format!("{annotation} {highlighted}"),
+            None => highlighted,
+        }
     }
 }
 
@@ -80,8 +183,18 @@ impl Default for Highlighter {
     }
 }
 
+/// Builds a [`Highlighter`]. Highlighters are applied in the order they were added, and text claimed by one is left
+/// alone by the ones after it, so add the more specific highlighters first: the threshold and epoch highlighters
+/// before the key-value highlighter, the duration highlighter before the quantity and date and time highlighters, the
+/// timestamp normalizer before the date and time highlighters, and all of these before the number highlighter.
 pub struct HighlightBuilder {
     highlighters: Vec<Arc<dyn Highlight>>,
+    line_highlighters: Vec<Arc<LineHighlighter>>,
+    json_highlighter: Option<Arc<JsonHighlighter>>,
+    timestamp_names: TimestampNames,
+    /// The normalizer and its position among the highlighters, built once the timestamp names are known
+    timestamp_normalizer: Option<(usize, TimestampFormat)>,
+    relative_time: Option<RelativeTimeConfig>,
     regex_errors: Vec<regex::Error>,
 }
 
@@ -91,6 +204,33 @@ impl HighlightBuilder {
         self
     }
 
+    /// Highlights numbers followed by a unit, such as `153ms`, `1.5GiB` or `87%`.
+    pub fn with_quantity_highlighter(&mut self, config: QuantityConfig) -> &mut Self {
+        self.try_add_highlighter(QuantityHighlighter::new(config));
+        self
+    }
+
+    /// Styles numbers by their value, such as the `1200` in `latency=1200ms`, limited to the keys and regexes in
+    /// `config`.
+    pub fn with_threshold_highlighter(&mut self, config: ThresholdConfig) -> &mut Self {
+        if !config.keys.is_empty() {
+            self.try_add_highlighter(ThresholdHighlighter::from_keys(&config));
+        }
+
+        for regex in &config.regexes {
+            self.try_add_highlighter(ThresholdHighlighter::from_regex(regex, &config));
+        }
+
+        self
+    }
+
+    /// Highlights Unix timestamps in the values of `config.keys`, such as `ts=1717171717`, optionally rendering them as
+    /// ISO-8601 dates.
+    pub fn with_epoch_highlighter(&mut self, config: EpochConfig) -> &mut Self {
+        self.try_add_highlighter(EpochHighlighter::new(config));
+        self
+    }
+
     pub fn with_uuid_highlighter(&mut self, config: UuidConfig) -> &mut Self {
         self.try_add_highlighter(UuidHighlighter::new(config));
         self
@@ -110,8 +250,25 @@ impl HighlightBuilder {
         self.try_add_highlighter(KeyValueHighlighter::new(config))
     }
 
+    /// Highlights durations such as `PT5M30S`, `1h2m3.5s` and `took 00:05:12.300`.
+    pub fn with_duration_highlighter(&mut self, config: DurationConfig) -> &mut Self {
+        self.try_add_highlighter(DurationHighlighter::new(config))
+    }
+
+    /// Rewrites timestamps into the format and zone of `config`, leaving them unstyled. Timestamps are found with the
+    /// names of the date and time highlighters.
+    pub fn with_timestamp_normalizer(&mut self, config: TimestampFormat) -> &mut Self {
+        self.timestamp_normalizer = Some((self.highlighters.len(), config));
+        self
+    }
+
+    /// Highlights dates and times. Textual dates such as `05/Mar/2024:10:00:00` are highlighted first, so that the
+    /// time highlighter doesn't mistake the end of the year for the start of a time.
     pub fn with_date_time_highlighters(&mut self, config: DateTimeConfig) -> &mut Self {
-        self.try_add_highlighter(TimeHighlighter::new(config))
+        self.timestamp_names = config.names.clone();
+
+        self.try_add_highlighter(TextualDateHighlighter::new(config.clone()))
+            .try_add_highlighter(TimeHighlighter::new(config.clone()))
             .try_add_highlighter(DateDashHighlighter::new(config))
     }
 
@@ -140,13 +297,46 @@ impl HighlightBuilder {
         self
     }
 
+    /// Highlights matches of the regex in `config` with a style picked by a hash of the highlighted text, so that the
+    /// same identifier always gets the same style.
+    pub fn with_hashed_regex_highlighter(&mut self, config: HashedRegexConfig) -> &mut Self {
+        self.try_add_highlighter(RegexpHighlighter::hashed(config));
+        self
+    }
+
     pub fn with_quote_highlighter(&mut self, config: QuotesConfig) -> &mut Self {
         self.try_add_highlighter(Ok(QuoteHighlighter::new(config)));
         self
     }
 
+    /// Highlights JSON objects and arrays. When highlighting through [`Highlighter::stream`], the first JSON
+    /// highlighter added also highlights documents spread over several lines.
     pub fn with_json_highlighter(&mut self, config: JsonConfig) -> &mut Self {
-        self.try_add_highlighter(Ok(JsonHighlighter::new(config)));
+        self.add_json_highlighter(JsonHighlighter::new(config))
+    }
+
+    /// Highlights Python dicts and JSON5 documents with the styles of `config`. On top of JSON, these may contain
+    /// single-quoted strings, unquoted keys, `True`, `False` and `None`, trailing commas and comments.
+    pub fn with_relaxed_json_highlighter(&mut self, config: JsonConfig) -> &mut Self {
+        self.add_json_highlighter(JsonHighlighter::relaxed(config))
+    }
+
+    /// Highlights struct dumps such as Rust's `Debug` output (`Request { id: 42 }`) and Go's `%+v` verb
+    /// (`&{ID:42}`).
+    pub fn with_struct_highlighter(&mut self, config: StructConfig) -> &mut Self {
+        self.try_add_highlighter(Ok(StructHighlighter::new(config)));
+        self
+    }
+
+    /// Highlights XML and HTML tags, comments and CDATA sections, including markup cut off at the end of the line.
+    pub fn with_xml_highlighter(&mut self, config: XmlConfig) -> &mut Self {
+        self.try_add_highlighter(Ok(XmlHighlighter::new(config)));
+        self
+    }
+
+    /// Highlights log levels such as `INFO`, `WARN` and `ERROR`, styled by their severity.
+    pub fn with_log_level_highlighter(&mut self, config: LogLevelConfig) -> &mut Self {
+        self.try_add_highlighter(LogLevelHighlighter::new(config));
         self
     }
 
@@ -165,6 +355,47 @@ impl HighlightBuilder {
         self
     }
 
+    /// Styles every line matching the regex in `config` with its style, including the gaps between highlighted tokens.
+    ///
+    /// Line highlighters are checked in the order they were added, and only the first match is applied.
+    pub fn with_line_regex_highlighter(&mut self, config: RegexConfig) -> &mut Self {
+        match LineHighlighter::from_regex(config) {
+            Ok(h) => self.line_highlighters.push(Arc::new(h)),
+            Err(e) => self.regex_errors.push(e),
+        }
+
+        self
+    }
+
+    /// Styles every line containing one of the keywords in `config` with its style, including the gaps between
+    /// highlighted tokens.
+    ///
+    /// Line highlighters are checked in the order they were added, and only the first match is applied.
+    pub fn with_line_keyword_highlighter(&mut self, config: KeywordConfig) -> &mut Self {
+        match LineHighlighter::from_keywords(config) {
+            Ok(h) => self.line_highlighters.push(Arc::new(h)),
+            Err(e) => self.regex_errors.push(e),
+        }
+
+        self
+    }
+
+    /// Prefixes timestamped lines of a [`LineStream`] with the time since the previous timestamped line, such as
+    /// `+0.153s`, styled by the size of the gap. [`Highlighter::apply`] doesn't add annotations. Timestamps are found
+    /// with the names of the date and time highlighters.
+    pub fn with_relative_time_annotations(&mut self, config: RelativeTimeConfig) -> &mut Self {
+        self.relative_time = Some(config);
+        self
+    }
+
+    fn add_json_highlighter(&mut self, json_highlighter: JsonHighlighter) -> &mut Self {
+        let json_highlighter = Arc::new(json_highlighter);
+
+        self.json_highlighter.get_or_insert_with(|| json_highlighter.clone());
+        self.highlighters.push(json_highlighter);
+        self
+    }
+
     fn try_add_highlighter<T: Highlight + 'static>(&mut self, highlighter: Result<T, regex::Error>) -> &mut Self {
         match highlighter {
             Ok(h) => self.highlighters.push(Arc::new(h)),
@@ -174,9 +405,31 @@ impl HighlightBuilder {
         self
     }
 
-    pub fn build(self) -> Result<Highlighter, Error> {
+    pub fn build(mut self) -> Result<Highlighter, Error> {
+        if let Some((index, format)) = self.timestamp_normalizer.take() {
+            match TimestampNormalizer::new(format, &self.timestamp_names) {
+                Ok(normalizer) => self.highlighters.insert(index, Arc::new(normalizer)),
+                Err(e) => self.regex_errors.push(e),
+            }
+        }
+
+        let relative_time = match self.relative_time.take() {
+            Some(config) => match RelativeTime::new(config, &self.timestamp_names) {
+                Ok(relative_time) => Some(Arc::new(relative_time)),
+                Err(e) => {
+                    self.regex_errors.push(e);
+                    None
+                }
+            },
+            None => None,
+        };
+
         match self.regex_errors.is_empty() {
-            true => Ok(Highlighter::new().with_highlighters(self.highlighters)),
+            true => Ok(Highlighter::new()
+                .with_highlighters(self.highlighters)
+                .with_line_highlighters(self.line_highlighters)
+                .with_json_highlighter(self.json_highlighter)
+                .with_relative_time(relative_time)),
             false => Err(Error::RegexErrors(self.regex_errors)),
         }
     }
@@ -5,6 +5,7 @@ use regex::{Captures, Error, Regex};
 
 pub struct DateDashHighlighter {
     regex_yyyy_xx_xx: Regex,
+    regex_yyyy_mm_dd_dotted: Regex,
     regex_xx_xx_yyyy: Regex,
     date: NuStyle,
     separator: NuStyle,
@@ -22,6 +23,20 @@ impl DateDashHighlighter {
                 ",
         )?;
 
+        // Dotted dates look like version numbers, so they are skipped after words like `version` and when more
+        // components follow, as in `version 2024.01.15` or `2024.01.15.3`
+        let regex_yyyy_mm_dd_dotted = Regex::new(
+            r"(?x)
+                (?P<version>\b(?i:version|ver|release|build|v)\s*[:=]?\s*)?
+                \b(?P<year>19\d{2}|20\d{2})          # Year: 1900-2099
+                (?P<separator>\.)
+                (?P<first>0[1-9]|1[0-2])             # Month: 01-12
+                (?P<separator2>\.)
+                (?P<second>0[1-9]|[12]\d|3[01])      # Day: 01-31
+                (?P<components>\.\d)?
+                ",
+        )?;
+
         let regex_xx_xx_yyyy = Regex::new(
             r"(?x)
                 (?P<first>0[1-9]|[12]\d|3[01])       # First number: 01-31
@@ -34,6 +49,7 @@ impl DateDashHighlighter {
 
         Ok(Self {
             regex_yyyy_xx_xx,
+            regex_yyyy_mm_dd_dotted,
             regex_xx_xx_yyyy,
             date: time_config.date.into(),
             separator: time_config.separator.into(),
@@ -41,6 +57,10 @@ impl DateDashHighlighter {
     }
 
     fn highlight_date(&self, caps: &Captures<'_>) -> Option<String> {
+        if caps.name("version").is_some() || caps.name("components").is_some() {
+            return None;
+        }
+
         let year = caps.name("year").map(|m| self.date.paint(m.as_str()));
         let first = caps.name("first").map(|m| self.date.paint(m.as_str()));
         let second = caps.name("second").map(|m| self.date.paint(m.as_str()));
@@ -66,7 +86,14 @@ impl DateDashHighlighter {
 
 impl Highlight for DateDashHighlighter {
     fn apply(&self, input: &str) -> String {
-        self.apply_regexes(input, &[&self.regex_yyyy_xx_xx, &self.regex_xx_xx_yyyy])
+        self.apply_regexes(
+            input,
+            &[
+                &self.regex_yyyy_xx_xx,
+                &self.regex_yyyy_mm_dd_dotted,
+                &self.regex_xx_xx_yyyy,
+            ],
+        )
     }
 }
 
@@ -104,6 +131,14 @@ mod tests {
                 "09/09/2022",
                 "[magenta]2022[reset][blue]/[reset][magenta]09[reset][blue]/[reset][magenta]09[reset]",
             ),
+            (
+                "2024.03.05",
+                "[magenta]2024[reset][blue].[reset][magenta]03[reset][blue].[reset][magenta]05[reset]",
+            ),
+            ("version 2024.01.15", "version 2024.01.15"),
+            ("v2024.01.15", "v2024.01.15"),
+            ("2024.01.15.3", "2024.01.15.3"),
+            ("2024.03-05", "2024.03-05"),
             ("3022-09-09", "3022-09-09"), // invalid year
             ("2022-19-39", "2022-19-39"), // invalid month
             ("2022/19/39", "2022/19/39"), // invalid month
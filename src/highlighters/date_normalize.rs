@@ -0,0 +1,287 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use regex::{Captures, Error, Regex};
+use std::fmt::Write;
+
+use crate::highlighter::Highlight;
+use crate::patterns::alternation;
+use crate::{TimestampFormat, TimestampNames, TimestampZone};
+
+/// Parses timestamps with a date, a time and an optional zone, such as `2024-03-05 10:00:00+02:00`.
+pub struct TimestampParser {
+    regex: Regex,
+    months: Vec<(String, u32)>,
+    assumed_zone: TimestampZone,
+}
+
+impl TimestampParser {
+    pub fn new(names: &TimestampNames, assumed_zone: TimestampZone) -> Result<Self, Error> {
+        let months = alternation(names.months.iter().flatten());
+        let weekdays = alternation(names.weekdays.iter());
+        let zones = alternation(names.zones.iter());
+
+        let regex = Regex::new(&format!(
+            r"(?x)
+            \b
+            (?:
+                (?P<year_iso>19\d{{2}}|20\d{{2}})[-/.]                 # 2024-03-05T10:00:00
+                (?P<month_iso>0[1-9]|1[0-2])[-/.]
+                (?P<day_iso>0[1-9]|[12]\d|3[01])[T\ ]
+                |
+                (?:(?:{weekdays}),?\ )?                                # Tue, 05 Mar 2024 10:00:00
+                (?P<day_rfc>0?[1-9]|[12]\d|3[01])\ (?P<month_rfc>{months})\ (?P<year_rfc>19\d{{2}}|20\d{{2}})\x20
+                |
+                (?P<day_apache>0[1-9]|[12]\d|3[01])/                   # 05/Mar/2024:10:00:00
+                (?P<month_apache>{months})/(?P<year_apache>19\d{{2}}|20\d{{2}}):
+            )
+            (?P<hour>[01]\d|2[0-3]):(?P<minute>[0-5]\d):(?P<second>[0-5]\d)
+            (?:[.,](?P<fraction>\d{{1,9}}))?
+            (?:
+                (?P<utc>Z)
+                |
+                (?P<offset>[+-](?:[01]\d|2[0-3])(?::?[0-5]\d)?)\b      # +02:00, -0500 or +02
+                |
+                \ (?P<spaced_offset>[+-](?:[01]\d|2[0-3]):?[0-5]\d)\b  # Only +02:00 or -0500 after a space
+                |
+                \ (?P<zone>{zones})\b
+            )?
+            (?P<region>\ ?\[[A-Za-z]+(?:/[\w+\-]+)+\])?                # [Europe/Oslo]
+            ",
+        ))?;
+
+        let months = names
+            .months
+            .iter()
+            .zip(1..)
+            .flat_map(|(names, number)| names.iter().map(move |name| (name.clone(), number)))
+            .collect();
+
+        Ok(Self {
+            regex,
+            months,
+            assumed_zone,
+        })
+    }
+
+    /// Returns the first timestamp in `input` that can be converted to UTC.
+    pub fn find(&self, input: &str) -> Option<DateTime<Utc>> {
+        self.regex.captures_iter(input).find_map(|caps| self.parse(&caps))
+    }
+
+    /// Converts a timestamp matched by the regex to UTC, or returns `None` if its zone is unknown.
+    fn parse(&self, caps: &Captures<'_>) -> Option<DateTime<Utc>> {
+        let field = |name: &str| {
+            ["iso", "rfc", "apache"]
+                .iter()
+                .find_map(|suffix| caps.name(&format!("{name}_{suffix}")))
+                .map(|m| m.as_str())
+        };
+        let number = |name: &str| caps.name(name).and_then(|m| m.as_str().parse::<u32>().ok());
+
+        let month = field("month")?;
+        let month = month.parse().ok().or_else(|| {
+            self.months
+                .iter()
+                .find(|(name, _)| name == month)
+                .map(|(_, number)| *number)
+        })?;
+        let nanos = match caps.name("fraction") {
+            Some(fraction) => format!("{:0<9}", fraction.as_str()).parse().ok()?,
+            None => 0,
+        };
+
+        let time = NaiveDate::from_ymd_opt(field("year")?.parse().ok()?, month, field("day")?.parse().ok()?)?
+            .and_hms_nano_opt(number("hour")?, number("minute")?, number("second")?, nanos)?;
+
+        let zone = if caps.name("utc").is_some() {
+            TimestampZone::Utc
+        } else if let Some(offset) = caps.name("offset").or_else(|| caps.name("spaced_offset")) {
+            TimestampZone::Fixed(parse_offset(offset.as_str())?)
+        } else if let Some(zone) = caps.name("zone") {
+            // Other abbreviations are ambiguous, such as `IST`, which is used in India, Ireland and Israel
+            matches!(zone.as_str(), "UTC" | "GMT").then_some(TimestampZone::Utc)?
+        } else if caps.name("region").is_some() {
+            return None;
+        } else {
+            self.assumed_zone
+        };
+
+        to_utc(&time, zone)
+    }
+}
+
+/// Rewrites timestamps into the format and zone of a [`TimestampFormat`].
+///
+/// The rewritten timestamps are left unstyled, so that the date and time highlighters that follow style them.
+pub struct TimestampNormalizer {
+    parser: TimestampParser,
+    format: String,
+    zone: TimestampZone,
+}
+
+impl TimestampNormalizer {
+    pub fn new(format: TimestampFormat, names: &TimestampNames) -> Result<Self, Error> {
+        Ok(Self {
+            parser: TimestampParser::new(names, format.assumed_zone)?,
+            format: format.format,
+            zone: format.zone,
+        })
+    }
+
+    /// Returns the rewritten timestamp, or `None` if the timestamp can't be converted.
+    fn rewrite(&self, caps: &Captures<'_>) -> Option<String> {
+        let utc = self.parser.parse(caps)?;
+        let format = &self.format;
+        let mut output = String::new();
+        let written = match self.zone {
+            TimestampZone::Local => write!(output, "{}", utc.with_timezone(&Local).format(format)),
+            TimestampZone::Utc => write!(output, "{}", utc.format(format)),
+            TimestampZone::Fixed(seconds) => write!(
+                output,
+                "{}",
+                utc.with_timezone(&FixedOffset::east_opt(seconds)?).format(format)
+            ),
+        };
+
+        // Invalid formats are only detected when writing
+        written.ok().map(|()| output)
+    }
+}
+
+fn to_utc(time: &NaiveDateTime, zone: TimestampZone) -> Option<DateTime<Utc>> {
+    match zone {
+        TimestampZone::Local => Local.from_local_datetime(time).earliest().map(|time| time.to_utc()),
+        TimestampZone::Utc => Some(time.and_utc()),
+        TimestampZone::Fixed(seconds) => FixedOffset::east_opt(seconds)?
+            .from_local_datetime(time)
+            .single()
+            .map(|time| time.to_utc()),
+    }
+}
+
+/// Parses an offset such as `+02:00`, `-0500` or `+02` into seconds east of UTC.
+fn parse_offset(offset: &str) -> Option<i32> {
+    let sign = match offset.starts_with('-') {
+        true => -1,
+        false => 1,
+    };
+    let digits: String = offset.chars().filter(char::is_ascii_digit).collect();
+    let hours: i32 = digits.get(..2)?.parse().ok()?;
+    let minutes: i32 = match digits.get(2..) {
+        Some(minutes) if !minutes.is_empty() => minutes.parse().ok()?,
+        _ => 0,
+    };
+
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+impl Highlight for TimestampNormalizer {
+    fn apply(&self, input: &str) -> String {
+        self.parser
+            .regex
+            .replace_all(input, |caps: &Captures<'_>| {
+                self.rewrite(caps).unwrap_or_else(|| caps[0].to_string())
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+    use crate::{DateTimeConfig, Highlighter};
+
+    use super::*;
+
+    #[test]
+    fn test_timestamp_normalizer() {
+        let highlighter = TimestampNormalizer::new(TimestampFormat::default(), &TimestampNames::default()).unwrap();
+
+        let cases = vec![
+            (
+                "2024-03-05T10:00:00.5+02:00 started",
+                "2024-03-05T08:00:00.500Z started",
+            ),
+            ("Date: Tue, 05 Mar 2024 10:00:00 GMT", "Date: 2024-03-05T10:00:00.000Z"),
+            ("[05/Mar/2024:10:00:00 -0500] GET", "[2024-03-05T15:00:00.000Z] GET"),
+            ("2024/03/05 23:30:00,123456", "2024-03-05T23:30:00.123Z"),
+            ("2024-03-05 10:00:00 CET", "2024-03-05 10:00:00 CET"),
+            ("2024-03-05T10:00:00[Europe/Oslo]", "2024-03-05T10:00:00[Europe/Oslo]"),
+            ("Mar  5 10:00:00 host sshd", "Mar  5 10:00:00 host sshd"),
+            ("2024-02-30 10:00:00 at 10:00:00", "2024-02-30 10:00:00 at 10:00:00"),
+            ("2024-03-05 10:00:00 +10 items", "2024-03-05T10:00:00.000Z +10 items"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(expected, highlighter.apply(input));
+        }
+    }
+
+    #[test]
+    fn test_timestamp_normalizer_with_fixed_zones() {
+        let format = |format: &str| TimestampFormat {
+            format: format.to_string(),
+            zone: TimestampZone::Fixed(7200),
+            assumed_zone: TimestampZone::Fixed(-5 * 3600),
+        };
+
+        let cases = vec![
+            (
+                format("%d.%m.%Y %H:%M:%S %:z"),
+                "2024-12-31 23:00:00",
+                "01.01.2025 06:00:00 +02:00",
+            ),
+            (format("%H:%M:%S%.f"), "2024-03-05T10:00:00.123Z", "12:00:00.123"),
+            (format("%Q"), "2024-03-05T10:00:00Z", "2024-03-05T10:00:00Z"),
+        ];
+
+        for (format, input, expected) in cases {
+            let highlighter = TimestampNormalizer::new(format, &TimestampNames::default()).unwrap();
+            assert_eq!(expected, highlighter.apply(input));
+        }
+    }
+
+    #[test]
+    fn test_normalized_timestamps_are_styled() {
+        let mut builder = Highlighter::builder();
+        builder
+            .with_timestamp_normalizer(TimestampFormat::default())
+            .with_date_time_highlighters(DateTimeConfig {
+                date: Style::new().fg(Color::Magenta),
+                time: Style::new().fg(Color::Blue),
+                zone: Style::new().fg(Color::Red),
+                separator: Style::new(),
+                ..DateTimeConfig::default()
+            });
+        let highlighter = builder.build().unwrap();
+
+        let actual = highlighter.apply("Tue, 05 Mar 2024 10:00:00 +0100");
+        let expected = "[magenta]2024[reset]-[magenta]03[reset]-[magenta]05[reset][red]T[reset][blue]09[reset]:[blue]00[reset]:[blue]00[reset].[blue]000[reset][red]Z[reset]";
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+
+    #[test]
+    fn test_normalizer_uses_the_names_of_the_date_time_highlighters() {
+        let mut config = DateTimeConfig {
+            date: Style::new(),
+            time: Style::new(),
+            zone: Style::new(),
+            separator: Style::new(),
+            ..DateTimeConfig::default()
+        };
+        config.names.months[2].push("Mär".to_string());
+
+        let mut builder = Highlighter::builder();
+        builder
+            .with_timestamp_normalizer(TimestampFormat::default())
+            .with_date_time_highlighters(config);
+        let highlighter = builder.build().unwrap();
+
+        let actual = highlighter.apply("Date: 05 Mär 2024 10:00:00 GMT");
+        let expected = "Date: 2024-03-05T10:00:00.000Z";
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::highlighter::Highlight;
+use crate::patterns::alternation;
+use crate::DateTimeConfig;
+use nu_ansi_term::Style as NuStyle;
+use regex::{Captures, Error, Regex};
+
+/// Highlights dates with month names, such as `Jan  5` (syslog), `Tue, 05 Mar 2024` (RFC 2822) and `05/Mar/2024`
+/// (Apache).
+pub struct TextualDateHighlighter {
+    regex: Regex,
+    date: NuStyle,
+    separator: NuStyle,
+}
+
+impl TextualDateHighlighter {
+    pub fn new(time_config: DateTimeConfig) -> Result<Self, Error> {
+        let months = alternation(time_config.names.months.iter().flatten());
+        let weekdays = alternation(time_config.names.weekdays.iter());
+
+        // Group names starting with `separator` are styled as separators, those starting with `time` are left to the
+        // time highlighter, and all others are styled as dates
+        let regex = Regex::new(&format!(
+            r"(?x)
+            \b
+            (?:
+                (?:(?P<weekday_rfc>{weekdays})(?P<separator_weekday_rfc>,?\ ))?   # Tue, 05 Mar 2024
+                (?P<day_rfc>0?[1-9]|[12]\d|3[01])(?P<separator_rfc>\ )
+                (?P<month_rfc>{months})(?P<separator2_rfc>\ )
+                (?P<year_rfc>19\d{{2}}|20\d{{2}})\b
+                |
+                (?P<day_apache>0[1-9]|[12]\d|3[01])(?P<separator_apache>/)         # 05/Mar/2024:10:00:00
+                (?P<month_apache>{months})(?P<separator2_apache>/)
+                (?P<year_apache>19\d{{2}}|20\d{{2}})\b(?P<separator_time_apache>:)?
+                |
+                (?:(?P<weekday_syslog>{weekdays})(?P<separator_weekday_syslog>\ ))?  # Jan  5 14:02:11
+                (?P<month_syslog>{months})(?P<separator_syslog>\ {{1,2}})
+                (?P<day_syslog>0?[1-9]|[12]\d|3[01])
+                (?P<time_syslog>\ (?:[01]\d|2[0-3]):[0-5]\d(?::[0-5]\d)?)\b         # Prose has no time after the day
+            )
+            ",
+        ))?;
+
+        Ok(Self {
+            regex,
+            date: time_config.date.into(),
+            separator: time_config.separator.into(),
+        })
+    }
+
+    fn highlight_date(&self, caps: &Captures<'_>) -> String {
+        let mut parts: Vec<(&str, regex::Match<'_>)> = self
+            .regex
+            .capture_names()
+            .flatten()
+            .filter_map(|name| caps.name(name).map(|m| (name, m)))
+            .collect();
+        parts.sort_by_key(|(_, m)| m.start());
+
+        parts
+            .iter()
+            .map(|(name, m)| match name {
+                _ if name.starts_with("separator") => self.separator.paint(m.as_str()).to_string(),
+                _ if name.starts_with("time") => m.as_str().to_string(),
+                _ => self.date.paint(m.as_str()).to_string(),
+            })
+            .collect()
+    }
+}
+
+impl Highlight for TextualDateHighlighter {
+    fn apply(&self, input: &str) -> String {
+        self.regex
+            .replace_all(input, |caps: &Captures<'_>| self.highlight_date(caps))
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_textual_date_highlighter() {
+        let config = DateTimeConfig {
+            date: Style::new().fg(Color::Magenta),
+            separator: Style::new().fg(Color::Blue),
+            ..DateTimeConfig::default()
+        };
+        let highlighter = TextualDateHighlighter::new(config).unwrap();
+
+        let cases = vec![
+            (
+                "Jan  5 14:02:11 host sshd",
+                "[magenta]Jan[reset][blue]  [reset][magenta]5[reset] 14:02:11 host sshd",
+            ),
+            (
+                "Date: Tue, 05 Mar 2024 10:00:00",
+                "Date: [magenta]Tue[reset][blue], [reset][magenta]05[reset][blue] [reset][magenta]Mar[reset][blue] [reset][magenta]2024[reset] 10:00:00",
+            ),
+            (
+                "[05/Mar/2024:10:00:00 +0000]",
+                "[[magenta]05[reset][blue]/[reset][magenta]Mar[reset][blue]/[reset][magenta]2024[reset][blue]:[reset]10:00:00 +0000]",
+            ),
+            (
+                "Tue Mar  5 10:00 boot",
+                "[magenta]Tue[reset][blue] [reset][magenta]Mar[reset][blue]  [reset][magenta]5[reset] 10:00 boot",
+            ),
+            ("Sep 12 and May 3 we met", "Sep 12 and May 3 we met"),
+            ("Marching 5 times", "Marching 5 times"),
+            ("No dates here!", "No dates here!"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_month_names_from_other_locales() {
+        let mut config = DateTimeConfig {
+            date: Style::new().fg(Color::Magenta),
+            separator: Style::new(),
+            ..DateTimeConfig::default()
+        };
+        config.names.months[2].push("Mär".to_string());
+
+        let highlighter = TextualDateHighlighter::new(config).unwrap();
+
+        let actual = highlighter.apply("05 Mär 2024");
+        let expected = "[magenta]05[reset] [magenta]Mär[reset] [magenta]2024[reset]";
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+}
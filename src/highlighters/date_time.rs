@@ -1,4 +1,5 @@
 use crate::highlighter::Highlight;
+use crate::patterns::alternation;
 use crate::DateTimeConfig;
 use nu_ansi_term::Style as NuStyle;
 use regex::{Error, Regex};
@@ -12,16 +13,28 @@ pub struct TimeHighlighter {
 
 impl TimeHighlighter {
     pub fn new(time_config: DateTimeConfig) -> Result<Self, Error> {
-        let regex = Regex::new(
+        let zones = alternation(time_config.names.zones.iter());
+
+        let regex = Regex::new(&format!(
             r"(?x)
             (?P<T>[T\s])?                              
             (?P<hours>[01]?\d|2[0-3])(?P<colon1>:)
             (?P<minutes>[0-5]\d)(?P<colon2>:)
             (?P<seconds>[0-5]\d)
             (?P<frac_sep>[.,:])?(?P<frac_digits>\d+)?  
-            (?P<tz>Z)?            
+            (?:
+                (?P<tz>Z)
+                |
+                (?P<offset>[+-](?:[01]\d|2[0-3])(?::?[0-5]\d)?)\b                        # +02:00, -0500 or +02
+                |
+                (?P<offset_space>\ )(?P<spaced_offset>[+-](?:[01]\d|2[0-3]):?[0-5]\d)\b   # Only +02:00 or -0500 after a space
+            )?
+            (?:
+                (?P<zone_space>\ )?
+                (?P<zone_name>(?:{zones})\b|\[[A-Za-z]+(?:/[\w+\-]+)+\])         # UTC or [Europe/Oslo]
+            )?
             ",
-        )?;
+        ))?;
 
         Ok(Self {
             regex,
@@ -52,6 +65,11 @@ impl Highlight for TimeHighlighter {
                     ("frac_sep", &self.separator),
                     ("frac_digits", &self.time),
                     ("tz", &self.zone),
+                    ("offset", &self.zone),
+                    ("offset_space", &NuStyle::new()),
+                    ("spaced_offset", &self.zone),
+                    ("zone_space", &NuStyle::new()),
+                    ("zone_name", &self.zone),
                 ];
 
                 parts.iter().fold(String::new(), |acc, (name, style)| {
@@ -77,6 +95,7 @@ mod tests {
             time: Style::new().fg(Color::Red),
             zone: Style::new().fg(Color::Blue),
             separator: Style::new().fg(Color::Yellow),
+            ..DateTimeConfig::default()
         };
         let highlighter = TimeHighlighter::new(config).unwrap();
 
@@ -107,7 +126,35 @@ mod tests {
             ),
             (
                 "2024-09-14T07:57:30.659+02:00",
-                "2024-09-14[blue]T[reset][red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset][yellow].[reset][red]659[reset]+02:00"
+                "2024-09-14[blue]T[reset][red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset][yellow].[reset][red]659[reset][blue]+02:00[reset]"
+            ),
+            (
+                "2024-09-14 07:57:30 -0500",
+                "2024-09-14[blue] [reset][red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset] [blue]-0500[reset]"
+            ),
+            (
+                "at 07:57:30 UTC and 07:57:30 CEST",
+                "at[blue] [reset][red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset] [blue]UTC[reset] and[blue] [reset][red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset] [blue]CEST[reset]"
+            ),
+            (
+                "2024-09-14T07:57:30+02:00[Europe/Oslo]",
+                "2024-09-14[blue]T[reset][red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset][blue]+02:00[reset][blue][Europe/Oslo][reset]"
+            ),
+            (
+                "07:57:30 UTCX",
+                "[red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset] UTCX"
+            ),
+            (
+                "10:00:00 +10 items",
+                "[red]10[reset][yellow]:[reset][red]00[reset][yellow]:[reset][red]00[reset] +10 items"
+            ),
+            (
+                "12:30:45 -05 retries",
+                "[red]12[reset][yellow]:[reset][red]30[reset][yellow]:[reset][red]45[reset] -05 retries"
+            ),
+            (
+                "07:57:30+02",
+                "[red]07[reset][yellow]:[reset][red]57[reset][yellow]:[reset][red]30[reset][blue]+02[reset]"
             ),
             ("No time here!", "No time here!"),
             ("2001:db8::ff00:42:8329", "2001:db8::ff00:42:8329"),
@@ -0,0 +1,205 @@
+use nu_ansi_term::Style as NuStyle;
+use regex::{Captures, Error, Regex};
+use std::fmt::Write;
+
+use crate::highlighter::Highlight;
+use crate::patterns::{alternation, glued_after, glued_quantities};
+use crate::DurationConfig;
+
+const GO_UNITS: &str = "h|ms|m|s|us|µs|ns";
+
+/// Highlights ISO-8601 durations such as `P1DT2H`, Go durations such as `1h2m3.5s` and elapsed times such as
+/// `took 00:05:12.300`.
+pub struct DurationHighlighter {
+    regex: Regex,
+    number: NuStyle,
+    unit: NuStyle,
+    separator: NuStyle,
+}
+
+impl DurationHighlighter {
+    pub fn new(config: DurationConfig) -> Result<Self, Error> {
+        let keywords = alternation(config.elapsed_keywords.iter());
+        let go = glued_quantities(GO_UNITS);
+
+        let regex = Regex::new(&format!(
+            r"(?x)
+            (?P<iso>
+                \bP(?:\d+(?:[.,]\d+)?[YMWD])*(?:T(?:\d+(?:[.,]\d+)?[HMS])+)?\b      # P1DT2H or PT5M30S
+            )
+            |
+            (?P<go>
+                \b{go}                                                                # 1h2m3.5s
+            )
+            |
+            (?:(?P<context>\b(?i:{keywords})\b[=:]?\s*)|\b)
+            (?P<elapsed>
+                (?P<days>\d+[-.])?(?P<hours>\d{{1,3}}):[0-5]\d:[0-5]\d(?:[.,]\d+)?\b  # 00:05:12.300 or 1-02:03:04
+            )
+            "
+        ))?;
+
+        Ok(Self {
+            regex,
+            number: config.number.into(),
+            unit: config.unit.into(),
+            separator: config.separator.into(),
+        })
+    }
+
+    /// Paints digits as numbers, letters as units and everything else as separators. The decimal point is part of the
+    /// number in `3.5s`, but a separator in `00:05:12.300`.
+    fn paint(&self, duration: &str, decimals: bool) -> String {
+        let style_of = |c: char| match c {
+            '0'..='9' => &self.number,
+            '.' | ',' if decimals => &self.number,
+            c if c.is_alphabetic() => &self.unit,
+            _ => &self.separator,
+        };
+
+        let mut output = String::new();
+        let mut start = 0;
+        let mut chars = duration.char_indices().peekable();
+
+        while let Some((_, c)) = chars.next() {
+            let style = style_of(c);
+            let end = chars.peek().map_or(duration.len(), |(i, _)| *i);
+            let continues = chars.peek().is_some_and(|(_, next)| style_of(*next) == style);
+
+            if !continues {
+                write!(output, "{}", style.paint(&duration[start..end])).unwrap();
+                start = end;
+            }
+        }
+
+        output
+    }
+
+    fn highlight_duration(&self, caps: &Captures<'_>, input: &str) -> Option<String> {
+        let matched = caps.get(0).unwrap();
+
+        if let Some(iso) = caps.name("iso") {
+            // `P` and `PT` on their own are words, not durations
+            return (iso.len() > 1 && !iso.as_str().ends_with('T')).then(|| self.paint(iso.as_str(), true));
+        }
+
+        if let Some(go) = caps.name("go") {
+            // Durations glued to more text, like `5min` or `2sec`, are left to other highlighters
+            return (!glued_after(input, matched.end())).then(|| self.paint(go.as_str(), true));
+        }
+
+        let elapsed = caps.name("elapsed")?;
+        let context = caps.name("context");
+        let hours: u32 = caps.name("hours")?.as_str().parse().ok()?;
+
+        // Without a keyword, `hh:mm:ss` is a time of day unless it can't be one
+        if context.is_none() && caps.name("days").is_none() && hours < 24 {
+            return None;
+        }
+
+        // Without a keyword, numbers after `/` or `:` belong to a date, such as `05/Mar/2024:10:00:00`
+        if context.is_none() && input[..elapsed.start()].ends_with(['/', ':']) {
+            return None;
+        }
+
+        // Without a keyword, numbers after `/` or `:` belong to a date, such as `05/Mar/2024:10:00:00`
+        if context.is_none() && input[..elapsed.start()].ends_with(['/', ':']) {
+            return None;
+        }
+
+        Some(format!(
+            "{}{}",
+            context.map_or("", |context| context.as_str()),
+            self.paint(elapsed.as_str(), false)
+        ))
+    }
+}
+
+impl Highlight for DurationHighlighter {
+    fn apply(&self, input: &str) -> String {
+        self.regex
+            .replace_all(input, |caps: &Captures<'_>| {
+                self.highlight_duration(caps, input)
+                    .unwrap_or_else(|| caps[0].to_string())
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+    use crate::{DateTimeConfig, Highlighter};
+
+    #[test]
+    fn test_duration_highlighter() {
+        let highlighter = DurationHighlighter::new(DurationConfig {
+            number: Style::new().fg(Color::Cyan),
+            unit: Style::new().fg(Color::Blue),
+            separator: Style::new().fg(Color::Yellow),
+            ..DurationConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            (
+                "timeout=PT5M30S",
+                "timeout=[blue]PT[reset][cyan]5[reset][blue]M[reset][cyan]30[reset][blue]S[reset]",
+            ),
+            (
+                "retention P1DT2H",
+                "retention [blue]P[reset][cyan]1[reset][blue]DT[reset][cyan]2[reset][blue]H[reset]",
+            ),
+            (
+                "done in 1h2m3.5s",
+                "done in [cyan]1[reset][blue]h[reset][cyan]2[reset][blue]m[reset][cyan]3.5[reset][blue]s[reset]",
+            ),
+            (
+                "job took 00:05:12.300",
+                "job took [cyan]00[reset][yellow]:[reset][cyan]05[reset][yellow]:[reset][cyan]12[reset][yellow].[reset][cyan]300[reset]",
+            ),
+            (
+                "Elapsed: 1-02:03:04",
+                "Elapsed: [cyan]1[reset][yellow]-[reset][cyan]02[reset][yellow]:[reset][cyan]03[reset][yellow]:[reset][cyan]04[reset]",
+            ),
+            (
+                "total 36:00:00",
+                "total [cyan]36[reset][yellow]:[reset][cyan]00[reset][yellow]:[reset][cyan]00[reset]",
+            ),
+            ("started at 10:51:19.251", "started at 10:51:19.251"),
+            ("HTTP PT 5min 2024-03-05T10:00:00", "HTTP PT 5min 2024-03-05T10:00:00"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_dates_before_the_date_time_highlighters() {
+        let mut builder = Highlighter::builder();
+        builder
+            .with_duration_highlighter(DurationConfig {
+                number: Style::new().fg(Color::Cyan),
+                ..DurationConfig::default()
+            })
+            .with_date_time_highlighters(DateTimeConfig {
+                date: Style::new().fg(Color::Magenta),
+                time: Style::new().fg(Color::Blue),
+                zone: Style::new().fg(Color::Red),
+                separator: Style::new(),
+                ..DateTimeConfig::default()
+            });
+        let highlighter = builder.build().unwrap();
+
+        let actual = highlighter.apply("[05/Mar/2024:10:00:00 +0000] GET");
+        let expected = "[[magenta]05[reset]/[magenta]Mar[reset]/[magenta]2024[reset]:[blue]10[reset]:[blue]00[reset]:[blue]00[reset] [red]+0000[reset]] GET";
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, SecondsFormat};
+use nu_ansi_term::Style as NuStyle;
+use regex::{Captures, Error, Regex};
+
+use crate::highlighter::Highlight;
+use crate::patterns::keyed_value_regex;
+use crate::{EpochConfig, EpochRender};
+
+/// 2000-01-01T00:00:00Z
+const EARLIEST: u64 = 946_684_800;
+/// 2100-01-01T00:00:00Z
+const LATEST: u64 = 4_102_444_800;
+
+pub struct EpochHighlighter {
+    regex: Regex,
+    style: NuStyle,
+    render: Option<EpochRender>,
+}
+
+impl EpochHighlighter {
+    pub fn new(config: EpochConfig) -> Result<Self, Error> {
+        let regex = keyed_value_regex(&config.keys, r"\d{9,19}(?:\.\d{1,9})?")?;
+
+        Ok(Self {
+            regex,
+            style: config.style.into(),
+            render: config.render,
+        })
+    }
+}
+
+/// Renders `value` as an ISO-8601 date in UTC, with as many fractional digits as the precision of the timestamp.
+///
+/// Returns `None` if `value` is not a plausible timestamp in seconds, milliseconds, microseconds or nanoseconds.
+fn render(value: &str) -> Option<String> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let integer: u64 = integer.parse().ok()?;
+
+    let (seconds, nanos, format) = match fraction.len() {
+        0 => [
+            (1, SecondsFormat::Secs),
+            (1_000, SecondsFormat::Millis),
+            (1_000_000, SecondsFormat::Micros),
+            (1_000_000_000, SecondsFormat::Nanos),
+        ]
+        .into_iter()
+        .find(|(divisor, _)| (EARLIEST..LATEST).contains(&(integer / divisor)))
+        .map(|(divisor, format)| {
+            (
+                integer / divisor,
+                (integer % divisor) * (1_000_000_000 / divisor),
+                format,
+            )
+        })?,
+        // Only seconds have a fractional part, such as `1717171717.123`
+        length => {
+            let nanos: u64 = format!("{fraction:0<9}").parse().ok()?;
+            let format = match length {
+                1..=3 => SecondsFormat::Millis,
+                4..=6 => SecondsFormat::Micros,
+                _ => SecondsFormat::Nanos,
+            };
+            (EARLIEST..LATEST)
+                .contains(&integer)
+                .then_some((integer, nanos, format))?
+        }
+    };
+
+    let date = DateTime::from_timestamp(i64::try_from(seconds).ok()?, u32::try_from(nanos).ok()?)?;
+
+    Some(date.to_rfc3339_opts(format, true))
+}
+
+impl Highlight for EpochHighlighter {
+    fn apply(&self, input: &str) -> String {
+        self.regex
+            .replace_all(input, |caps: &Captures<'_>| {
+                let matched = caps.get(0).unwrap();
+                let value = caps.name("value").unwrap();
+
+                // Values glued to more text, like `1717171717abc` or `1717171717.5.3`, are not timestamps
+                let glued_after = input[value.end()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.');
+
+                let rendered = match glued_after {
+                    true => None,
+                    false => render(value.as_str()),
+                };
+                let Some(rendered) = rendered else {
+                    return matched.as_str().to_string();
+                };
+
+                let painted = match self.render {
+                    None => self.style.paint(value.as_str()).to_string(),
+                    Some(EpochRender::Append) => format!(
+                        "{} ({})",
+                        self.style.paint(value.as_str()),
+                        self.style.paint(rendered.as_str())
+                    ),
+                    Some(EpochRender::Replace) => self.style.paint(rendered.as_str()).to_string(),
+                };
+
+                format!("{}{}", &input[matched.start()..value.start()], painted)
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_epoch_highlighter() {
+        let highlighter = EpochHighlighter::new(EpochConfig {
+            style: Style::new().fg(Color::Magenta),
+            ..EpochConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            (
+                "ts=1717171717 id=1717171717",
+                "ts=[magenta]1717171717[reset] id=1717171717",
+            ),
+            (
+                r#"{"timestamp": 1717171717123}"#,
+                r#"{"timestamp": [magenta]1717171717123[reset]}"#,
+            ),
+            ("time: 1717171717.5", "time: [magenta]1717171717.5[reset]"),
+            ("ts=123456789 ts=99999999999999", "ts=123456789 ts=99999999999999"),
+            ("ts=1717171717abc", "ts=1717171717abc"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_epoch_rendering() {
+        let config = |render| EpochConfig {
+            style: Style::new().fg(Color::Magenta),
+            render: Some(render),
+            ..EpochConfig::default()
+        };
+        let append = EpochHighlighter::new(config(EpochRender::Append)).unwrap();
+        let replace = EpochHighlighter::new(config(EpochRender::Replace)).unwrap();
+
+        let cases = vec![
+            (
+                &append,
+                "ts=1717171717",
+                "ts=[magenta]1717171717[reset] ([magenta]2024-05-31T16:08:37Z[reset])",
+            ),
+            (
+                &replace,
+                "ts=1717171717123",
+                "ts=[magenta]2024-05-31T16:08:37.123Z[reset]",
+            ),
+            (
+                &replace,
+                "ts=1717171717123456",
+                "ts=[magenta]2024-05-31T16:08:37.123456Z[reset]",
+            ),
+            (
+                &replace,
+                r#"ts="1717171717123456789""#,
+                r#"ts="[magenta]2024-05-31T16:08:37.123456789Z[reset]""#,
+            ),
+            (
+                &replace,
+                "ts=1717171717.25",
+                "ts=[magenta]2024-05-31T16:08:37.250Z[reset]",
+            ),
+        ];
+
+        for (highlighter, input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+}
@@ -1,9 +1,18 @@
-use crate::highlighter::Highlight;
-use crate::JsonConfig;
+use crate::ansi::{ansi_color_code_without_reset, paint_around_highlights};
+use crate::highlighter::{Highlight, Highlighter};
+use crate::highlighters::json::lexer::{Lexer, Token, TokenKind};
+use crate::highlighters::json::path::JsonPath;
+use crate::palette;
+use crate::{JsonConfig, JsonPrettyConfig, JsonValueStyle, NestedJsonMode};
 use nu_ansi_term::Style as NuStyle;
-use serde_json::Value;
 use std::fmt::Write;
 
+mod lexer;
+mod path;
+mod pretty;
+
+const MASK: &str = "***";
+
 pub struct JsonHighlighter {
     pub key: NuStyle,
     pub quote_token: NuStyle,
@@ -11,6 +20,15 @@ pub struct JsonHighlighter {
     pub square_bracket: NuStyle,
     pub comma: NuStyle,
     pub colon: NuStyle,
+    pub number: NuStyle,
+    pub boolean: NuStyle,
+    pub null: NuStyle,
+    string: String,
+    path_rules: Vec<(JsonPath, JsonValueStyle)>,
+    pretty: Option<JsonPrettyConfig>,
+    nested_json: Option<NestedJsonMode>,
+    inner: Option<Highlighter>,
+    relaxed: bool,
 }
 
 impl JsonHighlighter {
@@ -22,82 +40,426 @@ impl JsonHighlighter {
             square_bracket: config.square_bracket.into(),
             comma: config.comma.into(),
             colon: config.colon.into(),
+            number: config.number.into(),
+            boolean: config.boolean.into(),
+            null: config.null.into(),
+            string: ansi_color_code_without_reset(config.string),
+            path_rules: config
+                .path_rules
+                .into_iter()
+                .map(|rule| (JsonPath::parse(&rule.path), rule.style))
+                .collect(),
+            pretty: config.pretty,
+            nested_json: config.nested_json,
+            inner: config.inner,
+            relaxed: false,
         }
     }
 
-    fn format_json(&self, value: &Value, output: &mut String) {
-        match value {
-            Value::Object(map) => {
-                write!(output, "{}", self.curly_bracket.paint("{")).unwrap();
-                let mut first = true;
-                for (key, val) in map {
-                    if !first {
-                        write!(output, "{}", self.comma.paint(",")).unwrap();
-                    }
-                    first = false;
+    /// Creates a highlighter that also accepts Python dicts and JSON5: single-quoted strings, unquoted keys, `True`,
+    /// `False` and `None`, trailing commas and comments.
+    pub fn relaxed(config: JsonConfig) -> Self {
+        Self {
+            relaxed: true,
+            ..Self::new(config)
+        }
+    }
 
-                    write!(
-                        output,
-                        " {}{}{}",
-                        self.quote_token.paint("\""),
-                        self.key.paint(key),
-                        self.quote_token.paint("\"")
-                    )
-                    .unwrap();
-                    write!(output, "{} ", self.colon.paint(":")).unwrap();
+    const fn lexer(&self) -> Lexer {
+        match self.relaxed {
+            true => Lexer::relaxed(),
+            false => Lexer::new(),
+        }
+    }
 
-                    self.format_json(val, output);
+    fn paint_tokens(&self, tokens: &[Token<'_>], output: &mut String) {
+        match &self.pretty {
+            Some(pretty) => pretty::paint_pretty(self, pretty, tokens, output),
+            None => {
+                let mut keys = Vec::new();
+                for token in tokens {
+                    self.paint_token(token, &mut keys, output);
                 }
-                write!(output, " {}", self.curly_bracket.paint("}")).unwrap();
-            }
-            Value::Array(array) => {
-                write!(output, "{}", self.square_bracket.paint("[")).unwrap();
-                let mut first = true;
-                for item in array {
-                    if !first {
-                        write!(output, "{} ", self.comma.paint(",")).unwrap();
-                    }
-                    first = false;
-
-                    self.format_json(item, output);
+            }
+        }
+    }
+
+    /// Paints a single token, keeping track of the key path in `keys`.
+    ///
+    /// `keys` holds the current key of every enclosing container, or `None` for arrays and for objects before their
+    /// first key.
+    fn paint_token<'a>(&self, token: &Token<'a>, keys: &mut Vec<Option<&'a str>>, output: &mut String) {
+        match token.kind {
+            TokenKind::CurlyBracket => {
+                open_or_close(keys, token.text);
+                write!(output, "{}", self.curly_bracket.paint(token.text)).unwrap();
+            }
+            TokenKind::SquareBracket => {
+                open_or_close(keys, token.text);
+                write!(output, "{}", self.square_bracket.paint(token.text)).unwrap();
+            }
+            TokenKind::Colon => write!(output, "{}", self.colon.paint(token.text)).unwrap(),
+            TokenKind::Comma => write!(output, "{}", self.comma.paint(token.text)).unwrap(),
+            TokenKind::Key => {
+                let (quote, content) = split_quotes(token.text);
+                if let Some(current) = keys.last_mut() {
+                    *current = Some(content);
                 }
-                write!(output, "{}", self.square_bracket.paint("]")).unwrap();
+                match quote.is_empty() {
+                    true => write!(output, "{}", self.key.paint(content)).unwrap(),
+                    false => write!(
+                        output,
+                        "{}{}{}",
+                        self.quote_token.paint(quote),
+                        self.key.paint(content),
+                        self.quote_token.paint(quote)
+                    )
+                    .unwrap(),
+                }
+            }
+            TokenKind::String | TokenKind::Number | TokenKind::Boolean | TokenKind::Null => {
+                let path: Vec<&str> = keys.iter().flatten().copied().collect();
+                let rule = self
+                    .path_rules
+                    .iter()
+                    .find(|(json_path, _)| json_path.matches(&path))
+                    .map(|(_, style)| style);
+
+                self.paint_value(token, rule, output);
+            }
+            TokenKind::Whitespace | TokenKind::Comment => output.push_str(token.text),
+        }
+    }
+
+    fn paint_value(&self, token: &Token<'_>, rule: Option<&JsonValueStyle>, output: &mut String) {
+        let (quote, content) = match token.kind {
+            TokenKind::String => split_quotes(token.text),
+            _ => ("", token.text),
+        };
+
+        let style = match rule {
+            Some(JsonValueStyle::Fixed(style)) => Some(*style),
+            Some(JsonValueStyle::ByValue(values)) => values
+                .iter()
+                .find(|(value, _)| value.eq_ignore_ascii_case(content))
+                .map(|(_, style)| *style),
+            Some(JsonValueStyle::Hashed(palette)) => palette::pick(palette, content),
+            Some(JsonValueStyle::Mask(style)) => {
+                write!(
+                    output,
+                    "{}{}{}",
+                    self.quote_token.paint(quote),
+                    NuStyle::from(*style).paint(MASK),
+                    self.quote_token.paint(quote)
+                )
+                .unwrap();
+                return;
             }
-            Value::String(s) => {
+            None => None,
+        };
+
+        match token.kind {
+            TokenKind::String if self.paint_nested_json(token.text, output) => {}
+            TokenKind::String => {
+                let color = match style {
+                    Some(style) => ansi_color_code_without_reset(style),
+                    None => self.string.clone(),
+                };
+                let content = match &self.inner {
+                    Some(inner) => inner.apply(content),
+                    None => content.to_string(),
+                };
                 write!(
                     output,
                     "{}{}{}",
-                    self.quote_token.paint("\""),
-                    s,
-                    self.quote_token.paint("\"")
+                    self.quote_token.paint(quote),
+                    paint_around_highlights(&color, &content),
+                    self.quote_token.paint(quote)
                 )
                 .unwrap();
             }
-            Value::Number(n) => {
-                write!(output, "{}", n).unwrap();
+            _ => {
+                let default_style = match token.kind {
+                    TokenKind::Number => self.number,
+                    TokenKind::Boolean => self.boolean,
+                    _ => self.null,
+                };
+                let style = style.map_or(default_style, NuStyle::from);
+                write!(output, "{}", style.paint(content)).unwrap();
             }
-            Value::Bool(b) => {
-                write!(output, "{}", b).unwrap();
+        }
+    }
+
+    /// Highlights a string value that itself contains a JSON object or array.
+    ///
+    /// Returns false without writing anything if the string does not contain JSON, or if the nested JSON can't be
+    /// highlighted in place without changing how it is escaped.
+    fn paint_nested_json(&self, string_token: &str, output: &mut String) -> bool {
+        let Some(mode) = self.nested_json else {
+            return false;
+        };
+
+        let Ok(unescaped) = serde_json::from_str::<String>(string_token) else {
+            return false;
+        };
+
+        if !unescaped.trim_start().starts_with(['{', '[']) {
+            return false;
+        }
+
+        let mut lexer = self.lexer();
+        let mut tokens = match lexer.lex(&unescaped) {
+            Some((mut tokens, consumed)) if lexer.is_complete() && unescaped[consumed..].trim().is_empty() => {
+                if consumed < unescaped.len() {
+                    tokens.push(Token {
+                        kind: TokenKind::Whitespace,
+                        text: &unescaped[consumed..],
+                    });
+                }
+                tokens
             }
-            Value::Null => {
-                write!(output, "null").unwrap();
+            _ => return false,
+        };
+
+        let (quote, content) = split_quotes(string_token);
+        let escaped: Vec<String>;
+
+        if mode == NestedJsonMode::InPlace {
+            escaped = tokens.iter().map(|token| escape_json(token.text)).collect();
+            if escaped.concat() != content {
+                return false;
             }
+
+            tokens = tokens
+                .iter()
+                .zip(&escaped)
+                .map(|(token, text)| Token { kind: token.kind, text })
+                .collect();
         }
+
+        write!(output, "{}", self.quote_token.paint(quote)).unwrap();
+        self.paint_tokens(&tokens, output);
+        write!(output, "{}", self.quote_token.paint(quote)).unwrap();
+
+        true
+    }
+
+    /// Highlights JSON objects and arrays found anywhere in the input, leaving the text around them as is.
+    ///
+    /// The source text of each document is kept as is, so stripping the escape codes from the output yields the input.
+    ///
+    /// Every byte is lexed at most once: when a candidate turns out to be invalid or cut off, the search continues
+    /// after the bytes that were already lexed, and the complete objects and arrays nested inside the candidate are
+    /// highlighted on their own.
+    fn highlight_embedded_json(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        let mut last_end = 0;
+        let mut search_from = 0;
+
+        while let Some(offset) = input[search_from..].find(['{', '[']) {
+            let start = search_from + offset;
+            search_from = start + 1;
+
+            if !can_start_document(input, start) {
+                continue;
+            }
+
+            let mut lexer = self.lexer();
+            let (tokens, consumed, valid) = lexer.lex_until_invalid(&input[start..]);
+
+            let documents = match valid && lexer.is_complete() {
+                true => vec![(0, tokens.len())],
+                false => complete_containers(&tokens),
+            };
+
+            let mut token_start = start;
+            let mut painted_until = 0;
+            for (first, last) in documents {
+                token_start += tokens[painted_until..first]
+                    .iter()
+                    .map(|token| token.text.len())
+                    .sum::<usize>();
+                let document_length: usize = tokens[first..last].iter().map(|token| token.text.len()).sum();
+
+                output.push_str(&input[last_end..token_start]);
+                self.paint_tokens(&tokens[first..last], &mut output);
+                token_start += document_length;
+                last_end = token_start;
+                painted_until = last;
+            }
+
+            // The lexer always accepts the opening bracket, so the search moves forward
+            search_from = start + consumed;
+        }
+
+        output.push_str(&input[last_end..]);
+
+        output
+    }
+
+    /// Highlights the parts of `line` that belong to a JSON document spanning several lines.
+    ///
+    /// `document` holds the state of the document that is still open at the end of the previous line, and is updated
+    /// for the next line. Documents that start and end on the same line are left to [`Highlight::apply`].
+    pub fn apply_to_line(&self, line: &str, document: &mut Option<JsonDocument>) -> String {
+        let mut output = String::with_capacity(line.len());
+        let mut search_from = 0;
+
+        // If the open document doesn't continue on this line, the previous lines were not the start of a document
+        // after all, and this line is scanned on its own
+        if let Some(mut open_document) = document.take()
+            && let Some((tokens, consumed)) = open_document.lexer.lex(line)
+        {
+            open_document.paint_fragment(self, &tokens, &mut output);
+
+            if !open_document.lexer.is_complete() {
+                *document = Some(open_document);
+                return output;
+            }
+
+            search_from = consumed;
+        }
+
+        let rest_start = search_from;
+
+        while let Some(offset) = line[search_from..].find(['{', '[']) {
+            let start = search_from + offset;
+            search_from = start + 1;
+
+            if !can_start_document(line, start) {
+                continue;
+            }
+
+            let mut lexer = self.lexer();
+            let (tokens, consumed, valid) = lexer.lex_until_invalid(&line[start..]);
+
+            // Complete documents are left to `Highlight::apply`, and invalid ones are not lexed again
+            if !valid || lexer.is_complete() {
+                search_from = start + consumed;
+                continue;
+            }
+
+            // The document runs until the end of the line, so it continues on the next one
+            let mut open_document = JsonDocument {
+                lexer,
+                keys: Vec::new(),
+            };
+
+            output.push_str(&line[rest_start..start]);
+            open_document.paint_fragment(self, &tokens, &mut output);
+            *document = Some(open_document);
+
+            return output;
+        }
+
+        output.push_str(&line[rest_start..]);
+
+        output
+    }
+}
+
+/// A JSON document that is spread over several lines.
+pub struct JsonDocument {
+    lexer: Lexer,
+    keys: Vec<Option<String>>,
+}
+
+impl JsonDocument {
+    fn paint_fragment(&mut self, highlighter: &JsonHighlighter, tokens: &[Token<'_>], output: &mut String) {
+        let mut keys: Vec<Option<&str>> = self.keys.iter().map(Option::as_deref).collect();
+
+        for token in tokens {
+            highlighter.paint_token(token, &mut keys, output);
+        }
+
+        self.keys = keys.iter().map(|key| key.map(str::to_string)).collect();
     }
 }
 
+/// Returns the token ranges of the outermost objects and arrays that are complete, in the tokens of a document that is
+/// invalid or cut off.
+fn complete_containers(tokens: &[Token<'_>]) -> Vec<(usize, usize)> {
+    let mut open = Vec::new();
+    let mut complete: Vec<(usize, usize)> = Vec::new();
+
+    for (i, token) in tokens.iter().enumerate() {
+        if !matches!(token.kind, TokenKind::CurlyBracket | TokenKind::SquareBracket) {
+            continue;
+        }
+
+        match token.text {
+            "{" | "[" => open.push(i),
+            _ => {
+                let Some(first) = open.pop() else {
+                    continue;
+                };
+
+                // Containers nested inside this one are painted as part of it
+                while complete.last().is_some_and(|(nested, _)| *nested > first) {
+                    complete.pop();
+                }
+                complete.push((first, i + 1));
+            }
+        }
+    }
+
+    complete
+}
+
+/// Opening brackets glued to a word, like in `sshd[1234]`, are not the start of a JSON document.
+fn can_start_document(input: &str, start: usize) -> bool {
+    !input[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
 impl Highlight for JsonHighlighter {
     fn apply(&self, input: &str) -> String {
-        let json_value: Value = match serde_json::from_str(input) {
-            Ok(value) => value,
-            Err(_) => return input.to_string(),
-        };
+        self.highlight_embedded_json(input)
+    }
+}
+
+fn open_or_close(keys: &mut Vec<Option<&str>>, bracket: &str) {
+    match bracket {
+        "{" | "[" => keys.push(None),
+        _ => {
+            keys.pop();
+        }
+    }
+}
 
-        let mut output = String::new();
-        self.format_json(&json_value, &mut output);
+/// Splits a quoted token into its quote and the content between the quotes.
+///
+/// Strings of JSON highlighted in place inside another string are quoted with escaped quotes (`\"`), and unquoted
+/// keys of relaxed documents have no quotes at all.
+fn split_quotes(text: &str) -> (&str, &str) {
+    let quote_length = match text.as_bytes() {
+        [b'\\', b'"', ..] => 2,
+        [b'"' | b'\'', ..] => 1,
+        _ => 0,
+    };
 
-        output
+    (&text[..quote_length], &text[quote_length..text.len() - quote_length])
+}
+
+/// Escapes `text` for use inside a JSON string, using the shortest escape sequences.
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
     }
+
+    escaped
 }
 
 #[cfg(test)]
@@ -107,6 +469,24 @@ mod tests {
     use crate::tests::escape_code_converter::ConvertEscapeCodes;
 
     use super::*;
+    use crate::{DateTimeConfig, JsonPathRule, JsonPrettyConfig, JsonValueStyle, NestedJsonMode};
+
+    /// A config without styles, so that each test only sets the styles it checks
+    fn unstyled() -> JsonConfig {
+        JsonConfig {
+            key: Style::new(),
+            quote_token: Style::new(),
+            curly_bracket: Style::new(),
+            square_bracket: Style::new(),
+            comma: Style::new(),
+            colon: Style::new(),
+            string: Style::new(),
+            number: Style::new(),
+            boolean: Style::new(),
+            null: Style::new(),
+            ..JsonConfig::default()
+        }
+    }
 
     #[test]
     fn test_number_highlighter() {
@@ -117,13 +497,14 @@ mod tests {
             square_bracket: Style::new().fg(Color::Green),
             comma: Style::new().fg(Color::Red),
             colon: Style::new().fg(Color::Magenta),
+            ..unstyled()
         };
         let highlighter = JsonHighlighter::new(config);
 
         let cases = vec![
             (
                 r#"{ "name": "John Doe", "age": 43, "phones": [ "+44 1234567", "+44 2345678" ] }"#,
-                r#"[cyan]{[reset] [blue]"[reset][yellow]name[reset][blue]"[reset][magenta]:[reset] [blue]"[reset]John Doe[blue]"[reset][red],[reset] [blue]"[reset][yellow]age[reset][blue]"[reset][magenta]:[reset] 43[red],[reset] [blue]"[reset][yellow]phones[reset][blue]"[reset][magenta]:[reset] [green][[reset][blue]"[reset]+44 1234567[blue]"[reset][red],[reset] [blue]"[reset]+44 2345678[blue]"[reset][green]][reset] [cyan]}[reset]"#,
+                r#"[cyan]{[reset] [blue]"[reset][yellow]name[reset][blue]"[reset][magenta]:[reset] [blue]"[reset]John Doe[blue]"[reset][red],[reset] [blue]"[reset][yellow]age[reset][blue]"[reset][magenta]:[reset] 43[red],[reset] [blue]"[reset][yellow]phones[reset][blue]"[reset][magenta]:[reset] [green][[reset] [blue]"[reset]+44 1234567[blue]"[reset][red],[reset] [blue]"[reset]+44 2345678[blue]"[reset] [green]][reset] [cyan]}[reset]"#,
             ),
             (
                 r#"{ "name": "John", "age": 30 }"#,
@@ -137,4 +518,378 @@ mod tests {
             assert_eq!(expected, actual.convert_escape_codes());
         }
     }
+
+    #[test]
+    fn test_inner_highlighter_on_string_values() {
+        let mut builder = Highlighter::builder();
+        builder.with_date_time_highlighters(DateTimeConfig {
+            date: Style::new().fg(Color::Magenta),
+            separator: Style::new().fg(Color::Blue),
+            ..DateTimeConfig::default()
+        });
+        let inner = builder.build().unwrap();
+
+        let highlighter = JsonHighlighter::new(JsonConfig {
+            key: Style::new().fg(Color::Yellow),
+            quote_token: Style::new().fg(Color::Blue),
+            curly_bracket: Style::new().fg(Color::Cyan),
+            square_bracket: Style::new().fg(Color::Green),
+            comma: Style::new().fg(Color::Red),
+            colon: Style::new().fg(Color::Magenta),
+            inner: Some(inner),
+            ..unstyled()
+        });
+
+        let input = r#"{ "since": "2022-09-09" }"#;
+        let expected = r#"[cyan]{[reset] [blue]"[reset][yellow]since[reset][blue]"[reset][magenta]:[reset] [blue]"[reset][magenta]2022[reset][blue]-[reset][magenta]09[reset][blue]-[reset][magenta]09[reset][blue]"[reset] [cyan]}[reset]"#;
+
+        let actual = highlighter.apply(input);
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+
+    #[test]
+    fn test_embedded_json() {
+        let highlighter = JsonHighlighter::new(JsonConfig {
+            key: Style::new().fg(Color::Yellow),
+            quote_token: Style::new().fg(Color::Blue),
+            curly_bracket: Style::new().fg(Color::Cyan),
+            square_bracket: Style::new().fg(Color::Green),
+            comma: Style::new().fg(Color::Red),
+            colon: Style::new().fg(Color::Magenta),
+            ..unstyled()
+        });
+
+        let cases = vec![
+            (
+                r#"2024-01-01 INFO payload={"a":1} done"#,
+                r#"2024-01-01 INFO payload=[cyan]{[reset][blue]"[reset][yellow]a[reset][blue]"[reset][magenta]:[reset]1[cyan]}[reset] done"#,
+            ),
+            (
+                r#"ids=[1,2] and {"s":"}{"}"#,
+                r#"ids=[green][[reset]1[red],[reset]2[green]][reset] and [cyan]{[reset][blue]"[reset][yellow]s[reset][blue]"[reset][magenta]:[reset][blue]"[reset]}{[blue]"[reset][cyan]}[reset]"#,
+            ),
+            ("sshd[1234]: [INFO] {not json}", "sshd[1234]: [INFO] {not json}"),
+            ("unbalanced {\"a\": [1, 2}", "unbalanced {\"a\": [1, 2}"),
+            (
+                r#"cut off [{"a":1}, {"b""#,
+                r#"cut off [[cyan]{[reset][blue]"[reset][yellow]a[reset][blue]"[reset][magenta]:[reset]1[cyan]}[reset], {"b""#,
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_long_unbalanced_lines() {
+        let strict = JsonHighlighter::new(JsonConfig::default());
+        let relaxed = JsonHighlighter::relaxed(JsonConfig::default());
+
+        let inputs = ["[".repeat(20_000), r#"{"a": ["#.repeat(5_000), "{a: [".repeat(5_000)];
+
+        for highlighter in [&strict, &relaxed] {
+            for input in &inputs {
+                // Lexing from every bracket again takes seconds on these lines
+                let started = std::time::Instant::now();
+                let actual = highlighter.apply(input);
+                let streamed = highlighter.apply_to_line(input, &mut None);
+                assert!(started.elapsed() < std::time::Duration::from_millis(500));
+
+                assert_eq!(*input, strip_escape_codes(&actual));
+                assert_eq!(*input, strip_escape_codes(&streamed));
+            }
+        }
+    }
+
+    #[test]
+    fn test_value_styles() {
+        let highlighter = JsonHighlighter::new(JsonConfig {
+            key: Style::new().fg(Color::Yellow),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Cyan),
+            boolean: Style::new().fg(Color::Magenta),
+            null: Style::new().fg(Color::Red),
+            ..unstyled()
+        });
+
+        let cases = vec![
+            (
+                r#"{"s": "x", "n": -1.5, "b": true, "z": null}"#,
+                r#"{"[yellow]s[reset]": "[green]x[reset]", "[yellow]n[reset]": [cyan]-1.5[reset], "[yellow]b[reset]": [magenta]true[reset], "[yellow]z[reset]": [red]null[reset]}"#,
+            ),
+            (
+                r#"[1, ["a", [false, {"deep": null}]]]"#,
+                r#"[[cyan]1[reset], ["[green]a[reset]", [[magenta]false[reset], {"[yellow]deep[reset]": [red]null[reset]}]]]"#,
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_path_rules() {
+        let highlighter = JsonHighlighter::new(JsonConfig {
+            key: Style::new(),
+            quote_token: Style::new(),
+            curly_bracket: Style::new(),
+            square_bracket: Style::new(),
+            comma: Style::new(),
+            colon: Style::new(),
+            string: Style::new(),
+            number: Style::new(),
+            boolean: Style::new(),
+            null: Style::new(),
+            path_rules: vec![
+                JsonPathRule {
+                    path: "level".to_string(),
+                    style: JsonValueStyle::ByValue(vec![
+                        ("error".to_string(), Style::new().fg(Color::Red)),
+                        ("warn".to_string(), Style::new().fg(Color::Yellow)),
+                    ]),
+                },
+                JsonPathRule {
+                    path: "request.status".to_string(),
+                    style: JsonValueStyle::Fixed(Style::new().fg(Color::Green)),
+                },
+                JsonPathRule {
+                    path: "$..id".to_string(),
+                    style: JsonValueStyle::Fixed(Style::new().fg(Color::Magenta)),
+                },
+                JsonPathRule {
+                    path: "password".to_string(),
+                    style: JsonValueStyle::Mask(Style::new().fg(Color::Red)),
+                },
+                JsonPathRule {
+                    path: "thread".to_string(),
+                    style: JsonValueStyle::Hashed(vec![
+                        Style::new().fg(Color::Red),
+                        Style::new().fg(Color::Green),
+                        Style::new().fg(Color::Blue),
+                    ]),
+                },
+            ],
+            pretty: None,
+            nested_json: None,
+            inner: None,
+        });
+
+        let cases = vec![
+            (
+                r#"{"level": "ERROR", "msg": "error"}"#,
+                r#"{"level": "[red]ERROR[reset]", "msg": "error"}"#,
+            ),
+            (r#"{"level": "info"}"#, r#"{"level": "info"}"#),
+            (
+                r#"{"request": {"status": 200, "user": {"id": 7}}, "status": 500}"#,
+                r#"{"request": {"status": [green]200[reset], "user": {"id": [magenta]7[reset]}}, "status": 500}"#,
+            ),
+            (
+                r#"{"ids": [{"id": "a"}], "password": "hunter2"}"#,
+                r#"{"ids": [{"id": "[magenta]a[reset]"}], "password": "[red]***[reset]"}"#,
+            ),
+            (
+                r#"{"thread": "thread-1"} {"thread": "thread-2"} {"thread": "thread-1"}"#,
+                r#"{"thread": "[blue]thread-1[reset]"} {"thread": "[red]thread-2[reset]"} {"thread": "[blue]thread-1[reset]"}"#,
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_pretty() {
+        let highlighter = |pretty: JsonPrettyConfig| {
+            JsonHighlighter::new(JsonConfig {
+                curly_bracket: Style::new().fg(Color::Cyan),
+                pretty: Some(pretty),
+                ..unstyled()
+            })
+        };
+
+        let input = r#"{"a":1,"b":[1,2,3,4],"c":{"d":{"e":null}},"f":{},"g":[]}"#;
+
+        let actual = highlighter(JsonPrettyConfig::default()).apply(input);
+        let expected = r#"[cyan]{[reset]
+  "a": 1,
+  "b": [
+    1,
+    2,
+    3,
+    4
+  ],
+  "c": [cyan]{[reset]
+    "d": [cyan]{[reset]
+      "e": null
+    [cyan]}[reset]
+  [cyan]}[reset],
+  "f": [cyan]{[reset][cyan]}[reset],
+  "g": []
+[cyan]}[reset]"#;
+        assert_eq!(expected, actual.convert_escape_codes());
+
+        let actual = highlighter(JsonPrettyConfig {
+            indent: 4,
+            max_depth: Some(2),
+            max_array_length: Some(2),
+        })
+        .apply(input);
+        let expected = r#"[cyan]{[reset]
+    "a": 1,
+    "b": [
+        1,
+        2,
+        …
+    ],
+    "c": [cyan]{[reset]
+        "d": [cyan]{[reset]…[cyan]}[reset]
+    [cyan]}[reset],
+    "f": [cyan]{[reset][cyan]}[reset],
+    "g": []
+[cyan]}[reset]"#;
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+
+    #[test]
+    fn test_nested_json() {
+        let highlighter = |nested_json: NestedJsonMode| {
+            JsonHighlighter::new(JsonConfig {
+                key: Style::new().fg(Color::Yellow),
+                quote_token: Style::new().fg(Color::Blue),
+                number: Style::new().fg(Color::Cyan),
+                nested_json: Some(nested_json),
+                ..unstyled()
+            })
+        };
+
+        let input = r#"{"body":"{\"id\":42}"}"#;
+
+        let actual = highlighter(NestedJsonMode::InPlace).apply(input);
+        let expected = r#"{[blue]"[reset][yellow]body[reset][blue]"[reset]:[blue]"[reset]{[blue]\"[reset][yellow]id[reset][blue]\"[reset]:[cyan]42[reset]}[blue]"[reset]}"#;
+        assert_eq!(expected, actual.convert_escape_codes());
+
+        let actual = highlighter(NestedJsonMode::Unescaped).apply(input);
+        let expected = r#"{[blue]"[reset][yellow]body[reset][blue]"[reset]:[blue]"[reset]{[blue]"[reset][yellow]id[reset][blue]"[reset]:[cyan]42[reset]}[blue]"[reset]}"#;
+        assert_eq!(expected, actual.convert_escape_codes());
+
+        // Escapes that can't be reproduced in place leave the string as is
+        let input = r#"{"body":"{\"id\":\"\u00e9\"}"}"#;
+        let actual = highlighter(NestedJsonMode::InPlace).apply(input);
+        let expected =
+            r#"{[blue]"[reset][yellow]body[reset][blue]"[reset]:[blue]"[reset]{\"id\":\"\u00e9\"}[blue]"[reset]}"#;
+        assert_eq!(expected, actual.convert_escape_codes());
+
+        let input = r#"{"body":"not {json}"}"#;
+        let actual = highlighter(NestedJsonMode::Unescaped).apply(input);
+        let expected = r#"{[blue]"[reset][yellow]body[reset][blue]"[reset]:[blue]"[reset]not {json}[blue]"[reset]}"#;
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+
+    #[test]
+    fn test_relaxed() {
+        let config = || JsonConfig {
+            key: Style::new().fg(Color::Yellow),
+            quote_token: Style::new().fg(Color::Blue),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Cyan),
+            boolean: Style::new().fg(Color::Magenta),
+            null: Style::new().fg(Color::Red),
+            ..unstyled()
+        };
+        let highlighter = JsonHighlighter::relaxed(config());
+
+        let cases = vec![
+            (
+                "data {'key': 'value', 'ok': True, 'n': None}",
+                "data {[blue]'[reset][yellow]key[reset][blue]'[reset]: [blue]'[reset][green]value[reset][blue]'[reset], [blue]'[reset][yellow]ok[reset][blue]'[reset]: [magenta]True[reset], [blue]'[reset][yellow]n[reset][blue]'[reset]: [red]None[reset]}",
+            ),
+            (
+                "{retries: 3, /* seconds */ timeout: 1.5, hosts: ['a',], // done\n}",
+                "{[yellow]retries[reset]: [cyan]3[reset], /* seconds */ [yellow]timeout[reset]: [cyan]1.5[reset], [yellow]hosts[reset]: [[blue]'[reset][green]a[reset][blue]'[reset],], // done\n}",
+            ),
+            ("[INFO] {not json}", "[INFO] {not json}"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+
+        let strict = JsonHighlighter::new(config());
+        let input = "{'ok': True}";
+        assert_eq!(input, strict.apply(input).convert_escape_codes());
+
+        let pretty = JsonHighlighter::relaxed(JsonConfig {
+            quote_token: Style::new(),
+            pretty: Some(JsonPrettyConfig::default()),
+            ..config()
+        });
+        let input = "{'a': [1, 2,], /* note */ 'b': None,}";
+        let expected = "{\n  '[yellow]a[reset]': [\n    [cyan]1[reset],\n    [cyan]2[reset]\n  ],\n  '[yellow]b[reset]': [red]None[reset]\n}";
+        assert_eq!(expected, pretty.apply(input).convert_escape_codes());
+    }
+
+    #[test]
+    fn test_multi_line_document() {
+        let highlighter = JsonHighlighter::new(JsonConfig {
+            key: Style::new().fg(Color::Yellow),
+            curly_bracket: Style::new().fg(Color::Cyan),
+            square_bracket: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Red),
+            path_rules: vec![JsonPathRule {
+                path: "user.id".to_string(),
+                style: JsonValueStyle::Fixed(Style::new().fg(Color::Magenta)),
+            }],
+            ..unstyled()
+        });
+
+        let cases = vec![
+            ("INFO payload = {", "INFO payload = [cyan]{[reset]"),
+            (r#"  "user": {"#, r#"  "[yellow]user[reset]": [cyan]{[reset]"#),
+            (r#"    "id": 42,"#, r#"    "[yellow]id[reset]": [magenta]42[reset],"#),
+            (r#"    "tags": ["#, r#"    "[yellow]tags[reset]": [green][[reset]"#),
+            ("      1, 2", "      [red]1[reset], [red]2[reset]"),
+            ("    ]", "    [green]][reset]"),
+            ("  }", "  [cyan]}[reset]"),
+            ("} done 7", "[cyan]}[reset] done 7"),
+            ("INFO not json {", "INFO not json [cyan]{[reset]"),
+            ("plain text", "plain text"),
+            ("next 1", "next 1"),
+        ];
+
+        let mut document = None;
+        for (input, expected) in cases {
+            let actual = highlighter.apply_to_line(input, &mut document);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_source_formatting_is_preserved() {
+        let highlighter = JsonHighlighter::new(JsonConfig::default());
+
+        let cases = vec![
+            r#"{"a":1.0,"b":"\u00e9","c":[ true ,null ]}"#,
+            r#"{ "name" :  "é" , "nested": {"x": -1e-9} }"#,
+            r#"level=info payload=[{"id": 1}, {"id": 2}] rest"#,
+        ];
+
+        for input in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(strip_escape_codes(&actual), input);
+        }
+    }
+
+    fn strip_escape_codes(input: &str) -> String {
+        let regex = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        regex.replace_all(input, "").to_string()
+    }
 }
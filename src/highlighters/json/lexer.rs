@@ -0,0 +1,403 @@
+/// The kinds of tokens in a JSON document.
+///
+/// Every byte of the lexed input belongs to exactly one token, so concatenating the text of all tokens yields the
+/// original input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Whitespace,
+    CurlyBracket,
+    SquareBracket,
+    Colon,
+    Comma,
+    /// An object key, including its quotes.
+    Key,
+    /// A string value, including its quotes.
+    String,
+    Number,
+    Boolean,
+    Null,
+    /// A `//` or `/* */` comment, only produced by relaxed lexers.
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Container {
+    Object,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    Value,
+    ValueOrEnd,
+    Key,
+    KeyOrEnd,
+    Colon,
+    CommaOrEnd,
+    Done,
+}
+
+/// A JSON lexer that validates the structure of the document while splitting it into tokens.
+///
+/// The lexer keeps track of nesting, so it can be fed a document in several pieces.
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    stack: Vec<Container>,
+    expect: Expect,
+    relaxed: bool,
+}
+
+impl Lexer {
+    pub const fn new() -> Self {
+        Lexer {
+            stack: Vec::new(),
+            expect: Expect::Value,
+            relaxed: false,
+        }
+    }
+
+    /// Returns a lexer that also accepts the relaxed syntax of Python dicts and JSON5: single-quoted strings,
+    /// unquoted keys, `True`, `False` and `None`, trailing commas and comments.
+    pub const fn relaxed() -> Self {
+        Lexer {
+            stack: Vec::new(),
+            expect: Expect::Value,
+            relaxed: true,
+        }
+    }
+
+    /// Returns true once a complete top-level value has been lexed.
+    pub fn is_complete(&self) -> bool {
+        self.expect == Expect::Done
+    }
+
+    /// Lexes `input` until the top-level value is complete or the input runs out.
+    ///
+    /// Returns the tokens together with the number of bytes consumed, or `None` if the input is not valid JSON.
+    pub fn lex<'a>(&mut self, input: &'a str) -> Option<(Vec<Token<'a>>, usize)> {
+        match self.lex_until_invalid(input) {
+            (tokens, consumed, true) => Some((tokens, consumed)),
+            (_, _, false) => None,
+        }
+    }
+
+    /// Lexes `input` like [`Lexer::lex`], but stops at the first invalid token instead of discarding the tokens.
+    ///
+    /// Returns the valid tokens, the number of bytes they span and whether the input was valid.
+    pub fn lex_until_invalid<'a>(&mut self, input: &'a str) -> (Vec<Token<'a>>, usize, bool) {
+        let mut tokens = Vec::new();
+        let mut pos = 0;
+
+        while pos < input.len() && !self.is_complete() {
+            let Some(token) = self.next_token(&input[pos..]) else {
+                return (tokens, pos, false);
+            };
+
+            pos += token.text.len();
+            tokens.push(token);
+        }
+
+        (tokens, pos, true)
+    }
+
+    fn next_token<'a>(&mut self, rest: &'a str) -> Option<Token<'a>> {
+        let first = rest.chars().next()?;
+
+        if first.is_ascii_whitespace() {
+            let len = rest.find(|c: char| !c.is_ascii_whitespace()).unwrap_or(rest.len());
+            return Some(token(TokenKind::Whitespace, &rest[..len]));
+        }
+
+        if self.relaxed
+            && let Some(len) = comment_length(rest)
+        {
+            return Some(token(TokenKind::Comment, &rest[..len]));
+        }
+
+        match (first, self.expect) {
+            ('{', Expect::Value | Expect::ValueOrEnd) => {
+                self.stack.push(Container::Object);
+                self.expect = Expect::KeyOrEnd;
+                Some(token(TokenKind::CurlyBracket, &rest[..1]))
+            }
+            ('[', Expect::Value | Expect::ValueOrEnd) => {
+                self.stack.push(Container::Array);
+                self.expect = Expect::ValueOrEnd;
+                Some(token(TokenKind::SquareBracket, &rest[..1]))
+            }
+            ('}', Expect::KeyOrEnd | Expect::CommaOrEnd) if self.stack.last() == Some(&Container::Object) => {
+                self.stack.pop();
+                self.end_value();
+                Some(token(TokenKind::CurlyBracket, &rest[..1]))
+            }
+            (']', Expect::ValueOrEnd | Expect::CommaOrEnd) if self.stack.last() == Some(&Container::Array) => {
+                self.stack.pop();
+                self.end_value();
+                Some(token(TokenKind::SquareBracket, &rest[..1]))
+            }
+            (':', Expect::Colon) => {
+                self.expect = Expect::Value;
+                Some(token(TokenKind::Colon, &rest[..1]))
+            }
+            (',', Expect::CommaOrEnd) => {
+                // Relaxed lexers accept a trailing comma before the closing bracket
+                self.expect = match (self.stack.last(), self.relaxed) {
+                    (Some(Container::Object), false) => Expect::Key,
+                    (Some(Container::Object), true) => Expect::KeyOrEnd,
+                    (_, false) => Expect::Value,
+                    (_, true) => Expect::ValueOrEnd,
+                };
+                Some(token(TokenKind::Comma, &rest[..1]))
+            }
+            ('"', Expect::Key | Expect::KeyOrEnd) => {
+                let len = string_length(rest)?;
+                self.expect = Expect::Colon;
+                Some(token(TokenKind::Key, &rest[..len]))
+            }
+            ('\'', Expect::Key | Expect::KeyOrEnd) if self.relaxed => {
+                let len = string_length(rest)?;
+                self.expect = Expect::Colon;
+                Some(token(TokenKind::Key, &rest[..len]))
+            }
+            ('a'..='z' | 'A'..='Z' | '_' | '$', Expect::Key | Expect::KeyOrEnd) if self.relaxed => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+                    .unwrap_or(rest.len());
+                self.expect = Expect::Colon;
+                Some(token(TokenKind::Key, &rest[..len]))
+            }
+            ('\'', Expect::Value | Expect::ValueOrEnd) if self.relaxed => {
+                let len = string_length(rest)?;
+                self.end_value();
+                Some(token(TokenKind::String, &rest[..len]))
+            }
+            ('"', Expect::Value | Expect::ValueOrEnd) => {
+                let len = string_length(rest)?;
+                self.end_value();
+                Some(token(TokenKind::String, &rest[..len]))
+            }
+            ('-' | '0'..='9', Expect::Value | Expect::ValueOrEnd) => {
+                let len = rest
+                    .find(|c: char| !matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+                    .unwrap_or(rest.len());
+                if !is_valid_number(&rest[..len]) {
+                    return None;
+                }
+                self.end_value();
+                Some(token(TokenKind::Number, &rest[..len]))
+            }
+            ('a'..='z' | 'A'..='Z', Expect::Value | Expect::ValueOrEnd) => {
+                let len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+                let kind = match (&rest[..len], self.relaxed) {
+                    ("true" | "false", _) => TokenKind::Boolean,
+                    ("null", _) => TokenKind::Null,
+                    ("True" | "False", true) => TokenKind::Boolean,
+                    ("None", true) => TokenKind::Null,
+                    ("Infinity" | "NaN", true) => TokenKind::Number,
+                    _ => return None,
+                };
+                self.end_value();
+                Some(token(kind, &rest[..len]))
+            }
+            _ => None,
+        }
+    }
+
+    const fn end_value(&mut self) {
+        self.expect = match self.stack.is_empty() {
+            true => Expect::Done,
+            false => Expect::CommaOrEnd,
+        };
+    }
+}
+
+const fn token(kind: TokenKind, text: &str) -> Token<'_> {
+    Token { kind, text }
+}
+
+/// Returns the length of the string literal at the start of `input`, including both quotes.
+///
+/// The string is closed by the same kind of quote it was opened with.
+fn string_length(input: &str) -> Option<usize> {
+    let quote = input.chars().next()?;
+    let mut escaped = false;
+
+    for (i, ch) in input.char_indices().skip(1) {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return Some(i + 1),
+            c if (c as u32) < 0x20 => return None, // Control characters must be escaped
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns the length of the comment at the start of `input`, if any.
+///
+/// Line comments run until the end of the line, excluding the newline. Block comments must be closed.
+fn comment_length(input: &str) -> Option<usize> {
+    if input.starts_with("//") {
+        return Some(input.find('\n').unwrap_or(input.len()));
+    }
+
+    match input.starts_with("/*") {
+        true => input[2..].find("*/").map(|end| end + 4),
+        false => None,
+    }
+}
+
+/// Checks `text` against the JSON number grammar: `-?(0|[1-9]\d*)(\.\d+)?([eE][+-]?\d+)?`.
+fn is_valid_number(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+
+    let digits = |i: &mut usize| {
+        let start = *i;
+        while *i < bytes.len() && bytes[*i].is_ascii_digit() {
+            *i += 1;
+        }
+        *i - start
+    };
+
+    if bytes.get(i) == Some(&b'-') {
+        i += 1;
+    }
+
+    match bytes.get(i) {
+        Some(b'0') => i += 1,
+        Some(b'1'..=b'9') => {
+            digits(&mut i);
+        }
+        _ => return false,
+    }
+
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        if digits(&mut i) == 0 {
+            return false;
+        }
+    }
+
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        if digits(&mut i) == 0 {
+            return false;
+        }
+    }
+
+    i == bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lex_all(input: &str) -> Option<Vec<(TokenKind, &str)>> {
+        let mut lexer = Lexer::new();
+        let (tokens, consumed) = lexer.lex(input)?;
+
+        match lexer.is_complete() && consumed == input.len() {
+            true => Some(tokens.iter().map(|t| (t.kind, t.text)).collect()),
+            false => None,
+        }
+    }
+
+    #[test]
+    fn test_lex_preserves_source() {
+        let input = r#"{ "a" : [1.0, -2e3, "é\"x"],"b":{"c":null,"d":true} }"#;
+        let tokens = lex_all(input).unwrap();
+
+        let concatenated: String = tokens.iter().map(|(_, text)| *text).collect();
+        assert_eq!(concatenated, input);
+        assert_eq!(tokens[2], (TokenKind::Key, r#""a""#));
+        assert_eq!(tokens[7], (TokenKind::Number, "1.0"));
+        assert_eq!(tokens[13], (TokenKind::String, r#""é\"x""#));
+    }
+
+    #[test]
+    fn test_lex_rejects_invalid_json() {
+        let cases = vec![
+            "{not json}",
+            r#"{"a" 1}"#,
+            r#"{"a": 1,}"#,
+            "[1, 2}",
+            "[01]",
+            "[1.]",
+            "[tru]",
+            r#"{"a": "unterminated}"#,
+        ];
+
+        for input in cases {
+            assert_eq!(lex_all(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_lex_stops_after_document() {
+        let mut lexer = Lexer::new();
+        let (_, consumed) = lexer.lex(r#"{"a":1} trailing"#).unwrap();
+
+        assert!(lexer.is_complete());
+        assert_eq!(consumed, 7);
+    }
+
+    #[test]
+    fn test_lex_in_pieces() {
+        let mut lexer = Lexer::new();
+
+        lexer.lex(r#"{"a": ["#).unwrap();
+        assert!(!lexer.is_complete());
+
+        lexer.lex("1, 2]}").unwrap();
+        assert!(lexer.is_complete());
+    }
+
+    #[test]
+    fn test_lex_relaxed() {
+        let cases = vec![
+            "{'key': 'value', 'ok': True, 'n': None}",
+            "{unquoted: 1, $id: 'x',}",
+            "[1, 2, 3,]",
+            "{\"a\": 1 /* block */, // line\n\"b\": NaN}",
+        ];
+
+        for input in cases {
+            assert_eq!(lex_all(input), None, "{}", input);
+
+            let mut lexer = Lexer::relaxed();
+            let (tokens, consumed) = lexer.lex(input).unwrap();
+            assert!(lexer.is_complete(), "{}", input);
+            assert_eq!(consumed, input.len(), "{}", input);
+            assert_eq!(tokens.iter().map(|t| t.text).collect::<String>(), input);
+        }
+    }
+
+    #[test]
+    fn test_lex_relaxed_rejects_invalid_documents() {
+        let cases = vec![
+            "{not json}",
+            "[INFO]",
+            "[1,,]",
+            "{'a': 'unterminated}",
+            "[1 /* unterminated]",
+        ];
+
+        for input in cases {
+            let mut lexer = Lexer::relaxed();
+            let lexed = lexer.lex(input);
+            assert!(lexed.is_none() || !lexer.is_complete(), "{}", input);
+        }
+    }
+}
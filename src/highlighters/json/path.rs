@@ -0,0 +1,88 @@
+/// A JSON path selecting object values by their keys, such as `level`, `request.status` or `$..id`.
+///
+/// Array elements are transparent: they are selected by the path of the array that contains them. A segment preceded
+/// by `..` matches at any depth, and `*` matches any key.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Segment {
+    key: String,
+    descendant: bool,
+}
+
+impl JsonPath {
+    pub fn parse(path: &str) -> Self {
+        let path = path.strip_prefix('$').unwrap_or(path);
+        let mut segments = Vec::new();
+        let mut descendant = false;
+
+        // Splitting `a..b` on dots yields an empty part, which marks the next segment as a descendant
+        for (i, part) in path.split('.').enumerate() {
+            if part.is_empty() {
+                descendant = i > 0;
+                continue;
+            }
+
+            segments.push(Segment {
+                key: part.to_string(),
+                descendant,
+            });
+            descendant = false;
+        }
+
+        JsonPath { segments }
+    }
+
+    /// Returns true if the value reached through `keys`, starting at the root of the document, is selected.
+    pub fn matches(&self, keys: &[&str]) -> bool {
+        matches_segments(&self.segments, keys)
+    }
+}
+
+impl Segment {
+    fn matches(&self, key: &str) -> bool {
+        self.key == "*" || self.key == key
+    }
+}
+
+fn matches_segments(segments: &[Segment], keys: &[&str]) -> bool {
+    let Some((segment, rest)) = segments.split_first() else {
+        return keys.is_empty();
+    };
+
+    match segment.descendant {
+        true => (0..keys.len()).any(|i| segment.matches(keys[i]) && matches_segments(rest, &keys[i + 1..])),
+        false => keys
+            .split_first()
+            .is_some_and(|(key, keys)| segment.matches(key) && matches_segments(rest, keys)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_path_matching() {
+        let cases = vec![
+            ("level", vec!["level"], true),
+            ("level", vec!["request", "level"], false),
+            ("request.status", vec!["request", "status"], true),
+            ("$.request.status", vec!["request", "status"], true),
+            ("request.status", vec!["status"], false),
+            ("$..id", vec!["id"], true),
+            ("$..id", vec!["user", "group", "id"], true),
+            ("$..id", vec!["user", "id", "name"], false),
+            ("request..id", vec!["request", "user", "id"], true),
+            ("request..id", vec!["response", "user", "id"], false),
+            ("*.status", vec!["response", "status"], true),
+        ];
+
+        for (path, keys, expected) in cases {
+            assert_eq!(JsonPath::parse(path).matches(&keys), expected, "{} {:?}", path, keys);
+        }
+    }
+}
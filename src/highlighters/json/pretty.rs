@@ -0,0 +1,142 @@
+use crate::highlighters::json::lexer::{Token, TokenKind};
+use crate::highlighters::json::JsonHighlighter;
+use crate::JsonPrettyConfig;
+
+const ELLIPSIS: &str = "…";
+
+/// An object or array that is currently being written.
+struct Level {
+    is_array: bool,
+    close: usize,
+    elements: usize,
+}
+
+/// Paints `tokens` as indented, multi-line JSON, ignoring the whitespace, comments and trailing commas of the source.
+pub fn paint_pretty(
+    highlighter: &JsonHighlighter,
+    config: &JsonPrettyConfig,
+    tokens: &[Token<'_>],
+    output: &mut String,
+) {
+    let tokens: Vec<&Token<'_>> = tokens
+        .iter()
+        .filter(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .collect();
+    let tokens: Vec<&Token<'_>> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(i, t)| t.kind != TokenKind::Comma || !tokens.get(i + 1).is_some_and(|next| is_closing(next)))
+        .map(|(_, t)| *t)
+        .collect();
+    let mut keys = Vec::new();
+    let mut levels: Vec<Level> = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = tokens[i];
+
+        match token.kind {
+            TokenKind::CurlyBracket | TokenKind::SquareBracket if is_opening(token) => {
+                let close = matching_close(&tokens, i);
+                highlighter.paint_token(token, &mut keys, output);
+
+                let collapse = config.max_depth.is_some_and(|max| levels.len() >= max);
+                let is_empty = close == i + 1;
+                if collapse || is_empty {
+                    if !is_empty {
+                        output.push_str(ELLIPSIS);
+                    }
+                    highlighter.paint_token(tokens[close], &mut keys, output);
+                    i = close + 1;
+                    continue;
+                }
+
+                levels.push(Level {
+                    is_array: token.kind == TokenKind::SquareBracket,
+                    close,
+                    elements: 0,
+                });
+                new_line(output, config.indent, levels.len());
+
+                if let Some(skip_to) = elide_array(config, &levels, output) {
+                    i = skip_to;
+                    continue;
+                }
+            }
+            TokenKind::CurlyBracket | TokenKind::SquareBracket => {
+                levels.pop();
+                new_line(output, config.indent, levels.len());
+                highlighter.paint_token(token, &mut keys, output);
+            }
+            TokenKind::Comma => {
+                highlighter.paint_token(token, &mut keys, output);
+                if let Some(level) = levels.last_mut() {
+                    level.elements += 1;
+                }
+                new_line(output, config.indent, levels.len());
+
+                if let Some(skip_to) = elide_array(config, &levels, output) {
+                    i = skip_to;
+                    continue;
+                }
+            }
+            TokenKind::Colon => {
+                highlighter.paint_token(token, &mut keys, output);
+                output.push(' ');
+            }
+            _ => highlighter.paint_token(token, &mut keys, output),
+        }
+
+        i += 1;
+    }
+}
+
+/// Writes an ellipsis if the innermost array has reached its maximum length, and returns the index of its closing
+/// bracket so that the remaining elements can be skipped.
+fn elide_array(config: &JsonPrettyConfig, levels: &[Level], output: &mut String) -> Option<usize> {
+    let level = levels.last()?;
+    let max = config.max_array_length?;
+
+    match level.is_array && level.elements >= max {
+        true => {
+            output.push_str(ELLIPSIS);
+            Some(level.close)
+        }
+        false => None,
+    }
+}
+
+fn is_opening(token: &Token<'_>) -> bool {
+    matches!(token.text, "{" | "[")
+}
+
+fn is_closing(token: &Token<'_>) -> bool {
+    matches!(token.text, "}" | "]")
+}
+
+/// Returns the index of the bracket closing the one at `open`. The lexer guarantees that brackets are balanced.
+fn matching_close(tokens: &[&Token<'_>], open: usize) -> usize {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        if !matches!(token.kind, TokenKind::CurlyBracket | TokenKind::SquareBracket) {
+            continue;
+        }
+
+        match is_opening(token) {
+            true => depth += 1,
+            false => depth -= 1,
+        }
+
+        if depth == 0 {
+            return i;
+        }
+    }
+
+    tokens.len() - 1
+}
+
+fn new_line(output: &mut String, indent: usize, depth: usize) {
+    output.push('\n');
+    output.push_str(&" ".repeat(indent * depth));
+}
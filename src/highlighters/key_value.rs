@@ -1,44 +1,173 @@
 use nu_ansi_term::Style as NuStyle;
 use regex::{Captures, Error, Regex};
+use std::fmt::Write;
 
-use crate::highlighter::Highlight;
+use crate::ansi::{ansi_color_code_without_reset, paint_around_highlights};
+use crate::highlighter::{Highlight, Highlighter};
 use crate::KeyValueConfig;
 
 pub struct KeyValueHighlighter {
     regex: Regex,
     key: NuStyle,
     separator: NuStyle,
+    value: String,
+    quote_token: NuStyle,
+    logfmt: bool,
+    inner: Option<Highlighter>,
 }
 
 impl KeyValueHighlighter {
     pub fn new(config: KeyValueConfig) -> Result<Self, Error> {
-        let regex = Regex::new(r"(?P<space_or_start>(^)|\s)(?P<key>\w+\b)(?P<equals>=)")?;
+        // Without separators, the empty alternation would match after every word
+        let mut separators = match config.separators.is_empty() {
+            true => vec!["=".to_string()],
+            false => config.separators.clone(),
+        };
+        separators.sort_by_key(|separator| std::cmp::Reverse(separator.len()));
+        let separator_pattern = separators
+            .iter()
+            .map(|separator| regex::escape(separator))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        let key_pattern = match config.logfmt {
+            true => r"\w[\w.\-/]*", // Keys may contain dots, dashes and slashes
+            false => r"\w+\b",
+        };
+
+        let value_pattern = match config.logfmt {
+            true => {
+                r#"(?:
+                    (?P<open_quote>")
+                    (?P<quoted_value>(?:[^"\\]|\\.)*)   # Quoted values may contain spaces and escaped quotes
+                    (?P<close_quote>")
+                    |
+                    (?P<value>[^\s"]*)
+                )"#
+            }
+            false => "",
+        };
+
+        let regex = Regex::new(&format!(
+            r"(?x)
+            (?P<space_or_start>(^)|\s)
+            (?P<key>{key_pattern})
+            (?P<padding>[\ \t]*)
+            (?P<equals>{separator_pattern})
+            (?P<gap>\s*)
+            {value_pattern}
+            ",
+        ))?;
 
         Ok(Self {
             regex,
             key: config.key.into(),
             separator: config.separator.into(),
+            value: ansi_color_code_without_reset(config.value),
+            quote_token: config.quote_token.into(),
+            logfmt: config.logfmt,
+            inner: config.inner,
         })
     }
+
+    /// Rejects matches that are more likely to be times (`07:46:34`), URLs (`http://`) or IPv6 addresses, which are
+    /// owned by other highlighters.
+    fn is_key_value_pair(captures: &Captures, input: &str) -> bool {
+        let key = &captures["key"];
+        let separator = &captures["equals"];
+        let padding = &captures["padding"];
+        let gap = &captures["gap"];
+
+        if separator == "=" {
+            return padding.is_empty();
+        }
+
+        let key_starts_with_letter = key.starts_with(|c: char| c.is_alphabetic() || c == '_');
+        let padding_allowed = padding.is_empty() || separator.len() > 1;
+        let ends_line = captures.get(0).is_some_and(|m| m.end() == input.len());
+        let colon_followed_by_space = !separator.ends_with(':') || !gap.is_empty() || ends_line;
+
+        key_starts_with_letter && padding_allowed && colon_followed_by_space
+    }
+
+    fn highlight_value(&self, value: &str) -> String {
+        if value.is_empty() {
+            return String::new();
+        }
+
+        let content = match &self.inner {
+            Some(inner) => inner.apply(value),
+            None => value.to_string(),
+        };
+
+        paint_around_highlights(&self.value, &content)
+    }
+
+    /// Writes the highlighted pair to `output` and returns the end of the consumed input.
+    ///
+    /// The whitespace after the separator is only consumed together with a value, so that it can still start the next
+    /// pair, such as `b` in `a= b=1`.
+    fn highlight_pair(&self, captures: &Captures, output: &mut String) -> usize {
+        let equals = captures.name("equals").unwrap();
+        let gap = &captures["gap"];
+
+        write!(
+            output,
+            "{}{}{}{}",
+            &captures["space_or_start"],
+            self.key.paint(&captures["key"]),
+            &captures["padding"],
+            self.separator.paint(equals.as_str())
+        )
+        .unwrap();
+
+        let value = match captures.name("quoted_value") {
+            Some(quoted) => format!(
+                "{}{}{}",
+                self.quote_token.paint("\""),
+                self.highlight_value(quoted.as_str()),
+                self.quote_token.paint("\"")
+            ),
+            None => captures
+                .name("value")
+                .map(|v| self.highlight_value(v.as_str()))
+                .unwrap_or_default(),
+        };
+
+        // In logfmt, `key= value` is an empty value followed by unrelated text
+        if !self.logfmt || value.is_empty() || (equals.as_str() == "=" && !gap.is_empty()) {
+            return equals.end();
+        }
+
+        write!(output, "{gap}{value}").unwrap();
+
+        captures.get(0).map_or(equals.end(), |m| m.end())
+    }
 }
 
 impl Highlight for KeyValueHighlighter {
     fn apply(&self, input: &str) -> String {
-        self.regex
-            .replace_all(input, |captures: &Captures| {
-                let space_or_start = captures.name("space_or_start").map(|s| s.as_str()).unwrap_or_default();
-                let key = captures
-                    .name("key")
-                    .map(|k| format!("{}", self.key.paint(k.as_str())))
-                    .unwrap_or_default();
-                let equals_sign = captures
-                    .name("equals")
-                    .map(|e| format!("{}", self.separator.paint(e.as_str())))
-                    .unwrap_or_default();
-
-                format!("{}{}{}", space_or_start, key, equals_sign)
-            })
-            .to_string()
+        let mut output = String::with_capacity(input.len());
+        let mut last_end = 0;
+
+        while let Some(captures) = self.regex.captures_at(input, last_end) {
+            let matched = captures.get(0).unwrap();
+            output.push_str(&input[last_end..matched.start()]);
+
+            last_end = match Self::is_key_value_pair(&captures, input) {
+                true => self.highlight_pair(&captures, &mut output),
+                false => {
+                    // The text after the key may still start the next pair, such as `b` in `a = b=1`
+                    let key = captures.name("key").unwrap();
+                    output.push_str(&input[matched.start()..key.end()]);
+                    key.end()
+                }
+            };
+        }
+
+        output.push_str(&input[last_end..]);
+
+        output
     }
 }
 
@@ -46,7 +175,7 @@ impl Highlight for KeyValueHighlighter {
 mod tests {
     use crate::highlighter::Highlight;
     use crate::tests::escape_code_converter::ConvertEscapeCodes;
-    use crate::{Color, Style};
+    use crate::{Color, NumberConfig, Style};
 
     use super::*;
 
@@ -55,11 +184,17 @@ mod tests {
         let highlighter = KeyValueHighlighter::new(KeyValueConfig {
             key: Style::new().fg(Color::Red),
             separator: Style::new().fg(Color::Yellow),
+            ..KeyValueConfig::default()
         })
         .unwrap();
 
         let cases = vec![
             ("Entry key=value", "Entry [red]key[reset][yellow]=[reset]value"),
+            (
+                "2024-01-01 12:00:00 INFO a= b=1 c=2",
+                "2024-01-01 12:00:00 INFO [red]a[reset][yellow]=[reset] [red]b[reset][yellow]=[reset]1 [red]c[reset][yellow]=[reset]2",
+            ),
+            ("a = b=1", "a = [red]b[reset][yellow]=[reset]1"),
             ("No numbers here!", "No numbers here!"),
         ];
 
@@ -68,4 +203,128 @@ mod tests {
             assert_eq!(expected, actual.convert_escape_codes());
         }
     }
+
+    #[test]
+    fn test_logfmt() {
+        let highlighter = KeyValueHighlighter::new(KeyValueConfig {
+            key: Style::new().fg(Color::Red),
+            separator: Style::new().fg(Color::Yellow),
+            value: Style::new().fg(Color::Green),
+            quote_token: Style::new().fg(Color::Blue),
+            logfmt: true,
+            ..KeyValueConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            (
+                "http.status_code=200",
+                "[red]http.status_code[reset][yellow]=[reset][green]200[reset]",
+            ),
+            (
+                r#"msg="user logged in" user-id=42"#,
+                r#"[red]msg[reset][yellow]=[reset][blue]"[reset][green]user logged in[reset][blue]"[reset] [red]user-id[reset][yellow]=[reset][green]42[reset]"#,
+            ),
+            (
+                r#"err="say \"hi\"" empty="#,
+                r#"[red]err[reset][yellow]=[reset][blue]"[reset][green]say \"hi\"[reset][blue]"[reset] [red]empty[reset][yellow]=[reset]"#,
+            ),
+            (
+                "empty= next=1",
+                "[red]empty[reset][yellow]=[reset] [red]next[reset][yellow]=[reset][green]1[reset]",
+            ),
+            ("No pairs here!", "No pairs here!"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_logfmt_with_inner_highlighter() {
+        let mut builder = Highlighter::builder();
+        builder.with_number_highlighter(NumberConfig {
+            style: Style::new().fg(Color::Cyan),
+            ..NumberConfig::default()
+        });
+
+        let highlighter = KeyValueHighlighter::new(KeyValueConfig {
+            key: Style::new().fg(Color::Red),
+            separator: Style::new().fg(Color::Yellow),
+            value: Style::new().fg(Color::Green),
+            quote_token: Style::new().fg(Color::Blue),
+            logfmt: true,
+            inner: Some(builder.build().unwrap()),
+            ..KeyValueConfig::default()
+        })
+        .unwrap();
+
+        let input = r#"msg="took 42 ms""#;
+        let expected = r#"[red]msg[reset][yellow]=[reset][blue]"[reset][green]took [cyan]42[reset][green] ms[reset][blue]"[reset]"#;
+
+        let actual = highlighter.apply(input);
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+
+    #[test]
+    fn test_custom_separators() {
+        let highlighter = KeyValueHighlighter::new(KeyValueConfig {
+            key: Style::new().fg(Color::Red),
+            separator: Style::new().fg(Color::Yellow),
+            separators: vec![":".to_string(), "=".to_string(), "=>".to_string()],
+            ..KeyValueConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            (
+                "user: alice, status: 200",
+                "[red]user[reset][yellow]:[reset] alice, [red]status[reset][yellow]:[reset] 200",
+            ),
+            ("Host: example.com", "[red]Host[reset][yellow]:[reset] example.com"),
+            ("key=value", "[red]key[reset][yellow]=[reset]value"),
+            ("name => bob", "[red]name[reset] [yellow]=>[reset] bob"),
+            ("at 07:46:34 done", "at 07:46:34 done"),
+            ("see http://example.com", "see http://example.com"),
+            (
+                "from fe80::1 and 2001:db8::ff00:42:8329",
+                "from fe80::1 and 2001:db8::ff00:42:8329",
+            ),
+            ("took 12: seconds", "took 12: seconds"),
+            ("took 12: user: bob", "took 12: [red]user[reset][yellow]:[reset] bob"),
+            (
+                "user: alice status: 200",
+                "[red]user[reset][yellow]:[reset] alice [red]status[reset][yellow]:[reset] 200",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_empty_separators_fall_back_to_equals() {
+        let highlighter = KeyValueHighlighter::new(KeyValueConfig {
+            key: Style::new().fg(Color::Red),
+            separator: Style::new().fg(Color::Yellow),
+            separators: Vec::new(),
+            ..KeyValueConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            ("hello world foo", "hello world foo"),
+            ("key=value", "[red]key[reset][yellow]=[reset]value"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
 }
@@ -0,0 +1,97 @@
+use regex::{Error, Regex};
+
+use crate::ansi::{ansi_color_code_without_reset, paint_around_highlights};
+use crate::patterns::alternation;
+use crate::{KeywordConfig, RegexConfig};
+
+/// Styles an entire line when its predicate matches.
+///
+/// Unlike the other highlighters, a `LineHighlighter` is applied to the fully highlighted line. The line style is
+/// re-applied after every highlighted token, so it also covers the gaps between them without overwriting the tokens'
+/// own styles.
+pub struct LineHighlighter {
+    regex: Regex,
+    color: String,
+}
+
+impl LineHighlighter {
+    pub fn from_regex(config: RegexConfig) -> Result<Self, Error> {
+        let regex = Regex::new(config.regex.as_str())?;
+
+        Ok(Self {
+            regex,
+            color: ansi_color_code_without_reset(config.style),
+        })
+    }
+
+    pub fn from_keywords(config: KeywordConfig) -> Result<Self, Error> {
+        let keyword_pattern = alternation(config.words.iter());
+
+        let regex = Regex::new(&format!(r"\b({})\b", keyword_pattern))?;
+
+        Ok(Self {
+            regex,
+            color: ansi_color_code_without_reset(config.style),
+        })
+    }
+
+    /// Returns true if the unhighlighted `line` should be styled by this highlighter.
+    pub fn matches(&self, line: &str) -> bool {
+        self.regex.is_match(line)
+    }
+
+    pub fn apply(&self, highlighted_line: &str) -> String {
+        paint_around_highlights(&self.color, highlighted_line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::*;
+    use crate::tests::escape_code_converter::{ConvertEscapeCodes, ConvertHighlightCodes};
+
+    use super::*;
+
+    #[test]
+    fn test_keyword_line_highlighter() {
+        let highlighter = LineHighlighter::from_keywords(KeywordConfig {
+            words: vec!["ERROR".to_string()],
+            style: Style::new().on(Color::Red),
+        })
+        .unwrap();
+
+        assert!(highlighter.matches("2024-01-01 ERROR failed"));
+        assert!(!highlighter.matches("2024-01-01 INFO ERRORS are fine"));
+
+        let input = "ERROR took [cyan]42[reset] ms".to_string().convert_highlight_codes();
+        let expected = "[bg_red]ERROR took [cyan]42[reset][bg_red] ms[reset]";
+
+        let actual = highlighter.apply(&input);
+
+        assert_eq!(actual.convert_escape_codes(), expected);
+    }
+
+    #[test]
+    fn test_keyword_line_highlighter_without_words() {
+        let highlighter = LineHighlighter::from_keywords(KeywordConfig {
+            words: Vec::new(),
+            style: Style::new().on(Color::Red),
+        })
+        .unwrap();
+
+        assert!(!highlighter.matches("2024-01-01 ERROR failed"));
+        assert!(!highlighter.matches("hello world"));
+    }
+
+    #[test]
+    fn test_regex_line_highlighter() {
+        let highlighter = LineHighlighter::from_regex(RegexConfig {
+            regex: r"^\S+ DEBUG".to_string(),
+            style: Style::new().fg(Color::Blue),
+        })
+        .unwrap();
+
+        assert!(highlighter.matches("10:00:00 DEBUG connecting"));
+        assert!(!highlighter.matches("10:00:00 INFO DEBUG mode enabled"));
+    }
+}
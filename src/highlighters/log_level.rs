@@ -0,0 +1,143 @@
+use nu_ansi_term::Style as NuStyle;
+use regex::{Captures, Error, Regex};
+
+use crate::highlighter::Highlight;
+use crate::LogLevelConfig;
+
+pub struct LogLevelHighlighter {
+    regex: Regex,
+    trace: NuStyle,
+    debug: NuStyle,
+    info: NuStyle,
+    warn: NuStyle,
+    error: NuStyle,
+    fatal: NuStyle,
+}
+
+impl LogLevelHighlighter {
+    pub fn new(config: LogLevelConfig) -> Result<Self, Error> {
+        let levels = "trace|trc|debug|dbg|info|inf|warning|warn|wrn|error|err|fatal|ftl|critical|crit|panic";
+
+        // Short and lowercase spellings such as `err` and `trace` are common words, so they are only matched where a
+        // level is expected. Elsewhere, only uppercase levels are matched.
+        let regex = Regex::new(&format!(
+            r#"(?x)
+            (?P<position>
+                ^\s*(?:\[[^\]]*\d[^\]]*\]\s*|[\d:./T+Z,-]*\d[\d:./T+Z,-]*\s+)*   # The first word after a timestamp
+                |
+                \b(?i:level|lvl|severity)["']?\s*[=:]\s*["']?                     # level=info or "level": "info"
+            )
+            (?P<positioned>(?i:{levels}))\b
+            |
+            (?P<open>[\[<(])(?P<bracketed>(?i:{levels}))(?P<close>[\]>)])          # [info] or <DEBUG>
+            |
+            (?P<letter>(?i:\[[tdiwef]\]))                                            # [W]
+            |
+            \b(?P<word>TRACE|DEBUG|INFO|WARNING|WARN|ERROR|FATAL|CRITICAL|PANIC|[WE]\d{{4}})\b   # Also codes like E0423
+            "#
+        ))?;
+
+        Ok(Self {
+            regex,
+            trace: config.trace.into(),
+            debug: config.debug.into(),
+            info: config.info.into(),
+            warn: config.warn.into(),
+            error: config.error.into(),
+            fatal: config.fatal.into(),
+        })
+    }
+
+    /// Returns the style of a level, such as `warn`, `W0612` or `[W]`, by its first letter.
+    fn style_for(&self, level: &str) -> &NuStyle {
+        match level
+            .trim_start_matches('[')
+            .chars()
+            .next()
+            .map(|c| c.to_ascii_lowercase())
+        {
+            Some('t') => &self.trace,
+            Some('d') => &self.debug,
+            Some('i') => &self.info,
+            Some('w') => &self.warn,
+            Some('e') => &self.error,
+            _ => &self.fatal,
+        }
+    }
+}
+
+impl Highlight for LogLevelHighlighter {
+    fn apply(&self, input: &str) -> String {
+        self.regex
+            .replace_all(input, |caps: &Captures<'_>| {
+                let text = |name: &str| caps.name(name).map_or("", |m| m.as_str());
+                let level = ["positioned", "bracketed", "letter", "word"]
+                    .iter()
+                    .find_map(|name| caps.name(name))
+                    .map_or("", |m| m.as_str());
+
+                format!(
+                    "{}{}{}{}",
+                    text("position"),
+                    text("open"),
+                    self.style_for(level).paint(level),
+                    text("close")
+                )
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_log_level_highlighter() {
+        let highlighter = LogLevelHighlighter::new(LogLevelConfig {
+            trace: Style::new().fg(Color::White),
+            debug: Style::new().fg(Color::Blue),
+            info: Style::new().fg(Color::Green),
+            warn: Style::new().fg(Color::Yellow),
+            error: Style::new().fg(Color::Red),
+            fatal: Style::new().on(Color::Red),
+        })
+        .unwrap();
+
+        let cases = vec![
+            ("INFO Server started", "[green]INFO[reset] Server started"),
+            ("[W] disk almost full", "[yellow][W][reset] disk almost full"),
+            ("warning: unused variable", "[yellow]warning[reset]: unused variable"),
+            (
+                "error[E0423]: expected value",
+                "[red]error[reset][[red]E0423[reset]]: expected value",
+            ),
+            ("ts=1 level=error msg=oops", "ts=1 level=[red]error[reset] msg=oops"),
+            ("<DEBUG> trace enabled", "<[blue]DEBUG[reset]> trace enabled"),
+            (
+                "2024-01-01 12:00:00.123 info started",
+                "2024-01-01 12:00:00.123 [green]info[reset] started",
+            ),
+            (
+                "[2024-01-01 12:00:00] err: [wrn] retrying",
+                "[2024-01-01 12:00:00] [red]err[reset]: [[yellow]wrn[reset]] retrying",
+            ),
+            (r#"{"level": "dbg"}"#, r#"{"level": "[blue]dbg[reset]"}"#),
+            (
+                "got stack trace, value was inf, no error",
+                "got stack trace, value was inf, no error",
+            ),
+            ("FATAL out of memory", "[bg_red]FATAL[reset] out of memory"),
+            ("Information and errors are fine", "Information and errors are fine"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+}
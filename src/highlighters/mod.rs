@@ -1,15 +1,26 @@
 pub mod date_dash;
+pub mod date_normalize;
+pub mod date_text;
 pub mod date_time;
+pub mod duration;
+pub mod epoch;
 pub mod ip_v4;
 pub mod ip_v6;
 pub mod json;
 pub mod key_value;
 pub mod keyword;
+pub mod line;
+pub mod log_level;
 pub mod number;
 pub mod pointer;
+pub mod quantity;
 pub mod quote;
 pub mod regex;
+pub mod relative_time;
+pub mod struct_dump;
+pub mod threshold;
 pub mod unix_path;
 pub mod unix_process;
 pub mod url;
 pub mod uuid;
+pub mod xml;
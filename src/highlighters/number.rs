@@ -1,5 +1,6 @@
 use nu_ansi_term::Style as NuStyle;
 use regex::{Captures, Error, Regex};
+use std::fmt::Write;
 
 use crate::highlighter::Highlight;
 use crate::NumberConfig;
@@ -7,22 +8,52 @@ use crate::NumberConfig;
 pub struct NumberHighlighter {
     regex: Regex,
     style: NuStyle,
+    sign: NuStyle,
+    exponent: NuStyle,
+    radix_prefix: NuStyle,
 }
 
 impl NumberHighlighter {
     pub fn new(config: NumberConfig) -> Result<Self, Error> {
-        let regex = Regex::new(
+        let sign = match config.signs {
+            true => r"(?P<sign>[-+])?",
+            false => "",
+        };
+
+        let radix = match config.radix_prefixes {
+            true => r"(?P<radix_prefix>0[xXoObB])(?P<radix_digits>[0-9a-fA-F]+(?:_[0-9a-fA-F]+)*) |",
+            false => "",
+        };
+
+        let digits = match config.separators {
+            true => r"(?:\d{1,3}(?:,\d{3})+ | \d+(?:_\d+)*)(?:\.\d+(?:_\d+)*)?",
+            false => r"\d+(?:\.\d+)?",
+        };
+
+        let exponent = match config.scientific {
+            true => r"(?P<exponent>[eE][-+]?\d+)?",
+            false => "",
+        };
+
+        let regex = Regex::new(&format!(
             r"(?x)             # Enable verbose mode to allow comments and ignore whitespace
+            {sign}             # Optionally match a sign
             \b                 # Match a word boundary (start of the number)
-            \d+                # Match one or more digits (integer part of the number)
-            (\.\d+)?           # Optionally match a dot followed by one or more digits (fractional part of the number)
+            (?:
+                {radix}        # Optionally match a number with a radix prefix, such as 0xff
+                (?P<digits>{digits}) # Match the integer part and optionally a fractional part
+                {exponent}     # Optionally match an exponent
+            )
             \b                 # Match a word boundary (end of the number)
             ",
-        )?;
+        ))?;
 
         Ok(Self {
             regex,
             style: config.style.into(),
+            sign: config.sign.unwrap_or(config.style).into(),
+            exponent: config.exponent.unwrap_or(config.style).into(),
+            radix_prefix: config.radix_prefix.unwrap_or(config.style).into(),
         })
     }
 }
@@ -30,7 +61,41 @@ impl NumberHighlighter {
 impl Highlight for NumberHighlighter {
     fn apply(&self, input: &str) -> String {
         self.regex
-            .replace_all(input, |caps: &Captures<'_>| format!("{}", self.style.paint(&caps[0])))
+            .replace_all(input, |caps: &Captures<'_>| {
+                let mut output = String::new();
+
+                if let Some(sign) = caps.name("sign") {
+                    let glued_to_word = input[..sign.start()]
+                        .chars()
+                        .next_back()
+                        .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-' | '+'));
+
+                    match glued_to_word {
+                        true => output.push_str(sign.as_str()),
+                        false => write!(output, "{}", self.sign.paint(sign.as_str())).unwrap(),
+                    }
+                }
+
+                if let (Some(prefix), Some(digits)) = (caps.name("radix_prefix"), caps.name("radix_digits")) {
+                    write!(
+                        output,
+                        "{}{}",
+                        self.radix_prefix.paint(prefix.as_str()),
+                        self.style.paint(digits.as_str())
+                    )
+                    .unwrap();
+                }
+
+                if let Some(digits) = caps.name("digits") {
+                    write!(output, "{}", self.style.paint(digits.as_str())).unwrap();
+                }
+
+                if let Some(exponent) = caps.name("exponent") {
+                    write!(output, "{}", self.exponent.paint(exponent.as_str())).unwrap();
+                }
+
+                output
+            })
             .to_string()
     }
 }
@@ -47,6 +112,7 @@ mod tests {
     fn test_number_highlighter() {
         let highlighter = NumberHighlighter::new(NumberConfig {
             style: Style::new().fg(Color::Red),
+            ..NumberConfig::default()
         })
         .unwrap();
 
@@ -67,4 +133,66 @@ mod tests {
             assert_eq!(expected, actual.convert_escape_codes());
         }
     }
+
+    #[test]
+    fn test_number_formats() {
+        let highlighter = NumberHighlighter::new(NumberConfig {
+            style: Style::new().fg(Color::Red),
+            signs: true,
+            scientific: true,
+            radix_prefixes: true,
+            separators: true,
+            sign: Some(Style::new().fg(Color::Yellow)),
+            exponent: Some(Style::new().fg(Color::Blue)),
+            radix_prefix: Some(Style::new().fg(Color::Magenta)),
+        })
+        .unwrap();
+
+        let cases = vec![
+            (
+                "delta -3 and +42.5",
+                "delta [yellow]-[reset][red]3[reset] and [yellow]+[reset][red]42.5[reset]",
+            ),
+            (
+                "eps=1e-9 n=6.02E23",
+                "eps=[red]1[reset][blue]e-9[reset] n=[red]6.02[reset][blue]E23[reset]",
+            ),
+            (
+                "mask 0xff mode 0o755",
+                "mask [magenta]0x[reset][red]ff[reset] mode [magenta]0o[reset][red]755[reset]",
+            ),
+            (
+                "total 1_000_000 or 1,000,000",
+                "total [red]1_000_000[reset] or [red]1,000,000[reset]",
+            ),
+            (
+                "item x-3 and 1,2",
+                "item x-[red]3[reset] and [red]1[reset],[red]2[reset]",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_number_formats_are_opt_in() {
+        let highlighter = NumberHighlighter::new(NumberConfig {
+            style: Style::new().fg(Color::Red),
+            ..NumberConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            ("delta -3", "delta -[red]3[reset]"),
+            ("mask 0xff and 1_000", "mask 0xff and 1_000"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
 }
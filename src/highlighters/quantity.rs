@@ -0,0 +1,132 @@
+use nu_ansi_term::Style as NuStyle;
+use regex::{Captures, Error, Regex};
+use std::fmt::Write;
+
+use crate::highlighter::Highlight;
+use crate::patterns::{alternation, glued_after, glued_quantities, NUMBER};
+use crate::QuantityConfig;
+
+pub struct QuantityHighlighter {
+    regex: Regex,
+    segment: Regex,
+    magnitude: NuStyle,
+    unit: NuStyle,
+}
+
+impl QuantityHighlighter {
+    pub fn new(config: QuantityConfig) -> Result<Self, Error> {
+        let unit_pattern = alternation(config.units.iter());
+        let spaced_unit_pattern = alternation(config.units.iter().filter(|unit| unit.chars().count() > 1));
+        let glued = glued_quantities(&unit_pattern);
+
+        let regex = Regex::new(&format!(
+            r"(?x)
+            \b
+            (?:
+                {glued}                                 # One or more quantities without spaces, such as 2h30m
+                |
+                {NUMBER}\ (?:{spaced_unit_pattern})     # A quantity with a space, such as 512 KB
+            )
+            "
+        ))?;
+
+        let segment = Regex::new(&format!(
+            r"(?P<magnitude>{NUMBER})(?P<space>\ ?)(?P<unit>{unit_pattern})"
+        ))?;
+
+        Ok(Self {
+            regex,
+            segment,
+            magnitude: config.magnitude.into(),
+            unit: config.unit.into(),
+        })
+    }
+
+    /// Rejects matches that are part of a longer word (`5mph`) or of a version number (`1.5.3%`).
+    fn is_quantity(input: &str, start: usize, end: usize) -> bool {
+        !input[..start].ends_with('.') && !glued_after(input, end)
+    }
+}
+
+impl Highlight for QuantityHighlighter {
+    fn apply(&self, input: &str) -> String {
+        self.regex
+            .replace_all(input, |caps: &Captures<'_>| {
+                let quantity = caps.get(0).unwrap();
+                if !Self::is_quantity(input, quantity.start(), quantity.end()) {
+                    return quantity.as_str().to_string();
+                }
+
+                let mut output = String::new();
+                for segment in self.segment.captures_iter(quantity.as_str()) {
+                    write!(
+                        output,
+                        "{}{}{}",
+                        self.magnitude.paint(&segment["magnitude"]),
+                        &segment["space"],
+                        self.unit.paint(&segment["unit"])
+                    )
+                    .unwrap();
+                }
+
+                output
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_quantity_highlighter() {
+        let highlighter = QuantityHighlighter::new(QuantityConfig {
+            magnitude: Style::new().fg(Color::Cyan),
+            unit: Style::new().fg(Color::Blue),
+            ..QuantityConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            ("took 153ms", "took [cyan]153[reset][blue]ms[reset]"),
+            (
+                "ran for 2h30m",
+                "ran for [cyan]2[reset][blue]h[reset][cyan]30[reset][blue]m[reset]",
+            ),
+            (
+                "heap 1.5GiB of 512 KB",
+                "heap [cyan]1.5[reset][blue]GiB[reset] of [cyan]512[reset] [blue]KB[reset]",
+            ),
+            ("cpu 87% idle", "cpu [cyan]87[reset][blue]%[reset] idle"),
+            ("speed 5mph in 5 s, v1.5.3%", "speed 5mph in 5 s, v1.5.3%"),
+            ("No quantities here!", "No quantities here!"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_custom_units() {
+        let mut config = QuantityConfig {
+            magnitude: Style::new().fg(Color::Cyan),
+            unit: Style::new().fg(Color::Blue),
+            ..QuantityConfig::default()
+        };
+        config.units.push("req/s".to_string());
+
+        let highlighter = QuantityHighlighter::new(config).unwrap();
+
+        let actual = highlighter.apply("rate 120 req/s");
+        let expected = "rate [cyan]120[reset] [blue]req/s[reset]";
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
+}
@@ -1,15 +1,11 @@
-use nu_ansi_term::Style as NuStyle;
-
-use crate::highlighter::Highlight;
-use crate::highlighters::quote::State::{InsideQuote, OutsideQuote};
-use crate::style::Style;
+use crate::ansi::{ansi_color_code_without_reset, paint_around_highlights};
+use crate::highlighter::{Highlight, Highlighter};
 use crate::QuotesConfig;
 
-const RESET: &str = "\x1b[0m";
-
 pub struct QuoteHighlighter {
     quotes_token: char,
     color: String,
+    inner: Option<Highlighter>,
 }
 
 impl QuoteHighlighter {
@@ -19,17 +15,11 @@ impl QuoteHighlighter {
         Self {
             quotes_token: config.quotes_token,
             color,
+            inner: config.inner,
         }
     }
 }
 
-fn ansi_color_code_without_reset(style: Style) -> String {
-    let nu_style = NuStyle::from(style);
-    let styled_str = format!("{}", nu_style.paint(""));
-
-    styled_str.replace(RESET, "")
-}
-
 impl Highlight for QuoteHighlighter {
     fn apply(&self, input: &str) -> String {
         let quotes_count = input.chars().filter(|&ch| ch == self.quotes_token).count();
@@ -38,72 +28,42 @@ impl Highlight for QuoteHighlighter {
             return input.to_string();
         }
 
-        let mut state = OutsideQuote;
-        let mut output = String::new();
-
-        for ch in input.chars() {
-            match &mut state {
-                InsideQuote {
-                    color_inside_quote: color,
-                    potential_reset_code,
-                } => {
-                    if ch == self.quotes_token {
-                        output.push(ch);
-                        output.push_str(RESET);
-                        state = OutsideQuote;
-                        continue;
-                    }
-
-                    potential_reset_code.push(ch);
-                    if potential_reset_code.as_str() == RESET {
-                        output.push_str(potential_reset_code);
-                        output.push_str(color);
-                        potential_reset_code.clear();
-                    } else if !RESET.starts_with(potential_reset_code.as_str()) {
-                        output.push_str(potential_reset_code);
-                        potential_reset_code.clear();
-                    }
-                }
-                OutsideQuote => {
-                    if ch == self.quotes_token {
-                        output.push_str(&self.color);
-                        output.push(ch);
-                        state = InsideQuote {
-                            color_inside_quote: self.color.clone(),
-                            potential_reset_code: String::new(),
-                        };
-                        continue;
-                    }
-
-                    output.push(ch);
-                }
+        let mut output = String::with_capacity(input.len());
+
+        // Splitting on the quote token alternates between text outside and inside of quotes
+        for (i, segment) in input.split(self.quotes_token).enumerate() {
+            if i % 2 == 0 {
+                output.push_str(segment);
+                continue;
+            }
+
+            let content = match &self.inner {
+                Some(inner) => inner.apply(segment),
+                None => segment.to_string(),
             };
+            let quoted = format!("{}{}{}", self.quotes_token, content, self.quotes_token);
+
+            output.push_str(&paint_around_highlights(&self.color, &quoted));
         }
 
         output
     }
 }
 
-enum State {
-    InsideQuote {
-        color_inside_quote: String,
-        potential_reset_code: String,
-    },
-    OutsideQuote,
-}
-
 #[cfg(test)]
 mod tests {
     use crate::style::*;
     use crate::tests::escape_code_converter::{ConvertEscapeCodes, ConvertHighlightCodes};
 
     use super::*;
+    use crate::NumberConfig;
 
     #[test]
     fn test_multiple() {
         let highlighter = QuoteHighlighter::new(QuotesConfig {
             quotes_token: '"',
             style: Style::new().fg(Color::Yellow),
+            inner: None,
         });
 
         let cases = vec![
@@ -128,6 +88,7 @@ mod tests {
         let highlighter = QuoteHighlighter::new(QuotesConfig {
             quotes_token: '"',
             style: Style::new().fg(Color::Yellow),
+            inner: None,
         });
 
         let input = r#"Hello "abc [red]def[reset] ghi" World"#.to_string().convert_highlight_codes();
@@ -143,6 +104,7 @@ mod tests {
         let highlighter = QuoteHighlighter::new(QuotesConfig {
             quotes_token: '"',
             style: Style::new().fg(Color::Yellow),
+            inner: None,
         });
 
         let input = r#"Hello "abc def ghi World"#;
@@ -152,4 +114,27 @@ mod tests {
 
         assert_eq!(actual.convert_escape_codes(), expected);
     }
+
+    #[test]
+    fn test_inner_highlighter() {
+        let mut builder = Highlighter::builder();
+        builder.with_number_highlighter(NumberConfig {
+            style: Style::new().fg(Color::Cyan),
+            ..NumberConfig::default()
+        });
+        let inner = builder.build().unwrap();
+
+        let highlighter = QuoteHighlighter::new(QuotesConfig {
+            quotes_token: '"',
+            style: Style::new().fg(Color::Yellow),
+            inner: Some(inner),
+        });
+
+        let input = r#"Status "took 42 ms" for 7 items"#;
+        let expected = r#"Status [yellow]"took [cyan]42[reset][yellow] ms"[reset] for 7 items"#;
+
+        let actual = highlighter.apply(input);
+
+        assert_eq!(actual.convert_escape_codes(), expected);
+    }
 }
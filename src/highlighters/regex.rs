@@ -1,11 +1,13 @@
 use crate::highlighter::Highlight;
-use crate::RegexConfig;
+use crate::palette;
+use crate::{HashedRegexConfig, RegexConfig, Style};
 use nu_ansi_term::Style as NuStyle;
 use regex::{Error, Regex};
 
 pub struct RegexpHighlighter {
     regex: Regex,
     style: NuStyle,
+    palette: Vec<Style>,
 }
 
 impl RegexpHighlighter {
@@ -29,14 +31,33 @@ impl RegexpHighlighter {
         Ok(Self {
             regex,
             style: config.style.into(),
+            palette: Vec::new(),
         })
     }
+
+    /// Like [`RegexpHighlighter::new`], but picks the style of each match from the palette by a hash of the highlighted
+    /// text, so that the same text always gets the same style.
+    pub fn hashed(config: HashedRegexConfig) -> Result<Self, Error> {
+        let regex = Regex::new(config.regex.as_str())?;
+
+        Ok(Self {
+            regex,
+            style: NuStyle::new(),
+            palette: config.palette,
+        })
+    }
+
+    fn paint(&self, text: &str) -> String {
+        match palette::pick(&self.palette, text) {
+            Some(style) => format!("{}", NuStyle::from(style).paint(text)),
+            None => format!("{}", self.style.paint(text)),
+        }
+    }
 }
 
 impl Highlight for RegexpHighlighter {
     fn apply(&self, input: &str) -> String {
         let regex = &self.regex;
-        let color = &self.style;
         let capture_groups = regex.captures_len() - 1;
 
         let mut new_string = String::new();
@@ -54,7 +75,7 @@ impl Highlight for RegexpHighlighter {
                             // Add the text before the capturing group (from the start of the entire match)
                             new_string.push_str(&get_pre_match_text(input, entire_match.start(), captured.start()));
                             // Highlight the captured group
-                            new_string.push_str(&format!("{}", color.paint(captured.as_str())));
+                            new_string.push_str(&self.paint(captured.as_str()));
                             // Add the text after the capturing group (up to the end of the entire match)
                             new_string.push_str(&get_pre_match_text(input, captured.end(), entire_match.end()));
                         }
@@ -62,7 +83,7 @@ impl Highlight for RegexpHighlighter {
                     _ => {
                         // No capturing groups or more than one, highlight the entire match
                         let captured = entire_match.as_str();
-                        new_string.push_str(&format!("{}", color.paint(captured)));
+                        new_string.push_str(&self.paint(captured));
                     }
                 }
 
@@ -81,3 +102,36 @@ impl Highlight for RegexpHighlighter {
 fn get_pre_match_text(text: &str, start: usize, end: usize) -> String {
     text[start..end].to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_hashed_regex_highlighter() {
+        let highlighter = RegexpHighlighter::hashed(HashedRegexConfig {
+            regex: r"thread=(\S+)".to_string(),
+            palette: vec![
+                Style::new().fg(Color::Red),
+                Style::new().fg(Color::Green),
+                Style::new().fg(Color::Blue),
+            ],
+        })
+        .unwrap();
+
+        let cases = vec![
+            ("thread=thread-1 start", "thread=[blue]thread-1[reset] start"),
+            ("thread=thread-2 start", "thread=[red]thread-2[reset] start"),
+            ("thread=thread-1 stop", "thread=[blue]thread-1[reset] stop"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+use nu_ansi_term::Style as NuStyle;
+use regex::{Error, Regex};
+
+use crate::highlighters::date_normalize::TimestampParser;
+use crate::scale::style_for;
+use crate::{RelativeTimeConfig, ThresholdScale, TimestampNames, TimestampZone};
+
+/// A timestamp found on a line, such as `2024-03-05 10:00:00` or `10:00:00.153`.
+pub enum Timestamp {
+    Full(DateTime<Utc>),
+    TimeOfDay(NaiveTime),
+}
+
+/// Annotates lines with the time since the previous timestamped line, such as `+0.153s`.
+pub struct RelativeTime {
+    parser: TimestampParser,
+    time_of_day: Regex,
+    scale: ThresholdScale,
+}
+
+impl RelativeTime {
+    pub fn new(config: RelativeTimeConfig, names: &TimestampNames) -> Result<Self, Error> {
+        let time_of_day = Regex::new(
+            r"(?x)
+            \b
+            (?P<hours>[01]?\d|2[0-3]):(?P<minutes>[0-5]\d):(?P<seconds>[0-5]\d)
+            (?:[.,](?P<fraction>\d{1,9}))?
+            ",
+        )?;
+
+        Ok(Self {
+            // The zone doesn't matter as long as all timestamps without one are in the same zone
+            parser: TimestampParser::new(names, TimestampZone::Utc)?,
+            time_of_day,
+            scale: config.scale,
+        })
+    }
+
+    /// Returns the styled annotation for the first timestamp on `line` and replaces `previous` with it.
+    ///
+    /// Returns `None` if `line` has no timestamp, or if there is no previous timestamp of the same kind to compare with.
+    pub fn annotate(&self, line: &str, previous: &mut Option<Timestamp>) -> Option<String> {
+        let timestamp = self.find(line)?;
+        let delta = previous.as_ref().and_then(|previous| delta(previous, &timestamp));
+        *previous = Some(timestamp);
+
+        let delta = delta?;
+        let annotation = format_delta(delta);
+        // Lines out of order are styled by the size of the step back
+        let seconds = delta.num_milliseconds().abs() as f64 / 1000.0;
+
+        Some(match style_for(&self.scale, seconds) {
+            Some(style) => NuStyle::from(style).paint(annotation).to_string(),
+            None => annotation,
+        })
+    }
+
+    fn find(&self, line: &str) -> Option<Timestamp> {
+        if let Some(timestamp) = self.parser.find(line) {
+            return Some(Timestamp::Full(timestamp));
+        }
+
+        let caps = self.time_of_day.captures(line)?;
+        let number = |name: &str| caps.name(name).and_then(|m| m.as_str().parse::<u32>().ok());
+        let nanos = match caps.name("fraction") {
+            Some(fraction) => format!("{:0<9}", fraction.as_str()).parse().ok()?,
+            None => 0,
+        };
+
+        NaiveTime::from_hms_nano_opt(number("hours")?, number("minutes")?, number("seconds")?, nanos)
+            .map(Timestamp::TimeOfDay)
+    }
+}
+
+fn delta(previous: &Timestamp, next: &Timestamp) -> Option<TimeDelta> {
+    match (previous, next) {
+        (Timestamp::Full(previous), Timestamp::Full(next)) => Some(next.signed_duration_since(*previous)),
+        (Timestamp::TimeOfDay(previous), Timestamp::TimeOfDay(next)) => {
+            let delta = next.signed_duration_since(*previous);
+
+            // A large step back is more likely the clock passing midnight than lines out of order
+            match delta < -TimeDelta::hours(12) {
+                true => Some(delta + TimeDelta::days(1)),
+                false => Some(delta),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Formats `delta` as `+0.153s`, `+2m03.500s` or `+1h02m03s`.
+fn format_delta(delta: TimeDelta) -> String {
+    let sign = match delta < TimeDelta::zero() {
+        true => '-',
+        false => '+',
+    };
+    let millis = delta.num_milliseconds().unsigned_abs();
+    let (hours, minutes, seconds, millis) = (
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000,
+    );
+
+    match (hours, minutes) {
+        (0, 0) => format!("{sign}{seconds}.{millis:03}s"),
+        (0, _) => format!("{sign}{minutes}m{seconds:02}.{millis:03}s"),
+        _ => format!("{sign}{hours}h{minutes:02}m{seconds:02}s"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_relative_time() {
+        let relative_time = RelativeTime::new(
+            RelativeTimeConfig {
+                scale: ThresholdScale::Steps(vec![
+                    (1.0, Style::new().fg(Color::Yellow)),
+                    (10.0, Style::new().fg(Color::Red)),
+                ]),
+            },
+            &TimestampNames::default(),
+        )
+        .unwrap();
+
+        let lines = [
+            ("2024-03-05 10:00:00.000 start", None),
+            ("2024-03-05 10:00:00.153 step", Some("+0.153s")),
+            ("no timestamp here", None),
+            ("Tue, 05 Mar 2024 10:00:02 GMT slow", Some("[yellow]+1.847s[reset]")),
+            ("[05/Mar/2024:10:02:05 +0000] slower", Some("[red]+2m03.000s[reset]")),
+            ("2024-03-05T09:59:59Z out of order", Some("[red]-2m06.000s[reset]")),
+            ("23:59:59.900 time of day", None),
+            ("00:00:00.100 past midnight", Some("+0.200s")),
+            ("02:10:30 much later", Some("[red]+2h10m29s[reset]")),
+        ];
+
+        let mut previous = None;
+        for (line, expected) in lines {
+            let actual = relative_time.annotate(line, &mut previous);
+            assert_eq!(
+                expected.map(str::to_string),
+                actual.map(|actual| actual.convert_escape_codes())
+            );
+        }
+    }
+}
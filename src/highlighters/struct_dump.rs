@@ -0,0 +1,537 @@
+use crate::highlighter::Highlight;
+use crate::StructConfig;
+use nu_ansi_term::Style as NuStyle;
+use std::fmt::Write;
+
+/// Dumps nested deeper than this are rejected, which also bounds the recursion of the parser.
+const MAX_DEPTH: usize = 32;
+
+pub struct StructHighlighter {
+    type_name: NuStyle,
+    field: NuStyle,
+    separator: NuStyle,
+    bracket: NuStyle,
+    comma: NuStyle,
+    quote_token: NuStyle,
+    string: NuStyle,
+    number: NuStyle,
+    boolean: NuStyle,
+    null: NuStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    TypeName,
+    Field,
+    Separator,
+    Bracket,
+    Comma,
+    /// A string, including its quotes if it has any.
+    String,
+    Number,
+    Boolean,
+    Null,
+    /// Whitespace and other text that is not styled, such as the `..` of non-exhaustive structs.
+    Plain,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+}
+
+/// A recursive descent parser for struct dumps.
+///
+/// Every parsing method either consumes a complete part of the dump and returns `Some`, or returns `None` if the input
+/// does not have the expected shape, in which case the whole dump is discarded.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+    tokens: Vec<Token<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    const fn new(input: &'a str, pos: usize) -> Self {
+        Parser {
+            input,
+            pos,
+            depth: 0,
+            tokens: Vec::new(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn push(&mut self, kind: TokenKind, len: usize) {
+        self.tokens.push(Token {
+            kind,
+            text: &self.input[self.pos..self.pos + len],
+        });
+        self.pos += len;
+    }
+
+    fn eat(&mut self, kind: TokenKind, literal: &str) -> Option<()> {
+        self.rest().starts_with(literal).then(|| self.push(kind, literal.len()))
+    }
+
+    fn whitespace(&mut self) {
+        let rest = self.rest();
+        let len = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+        if len > 0 {
+            self.push(TokenKind::Plain, len);
+        }
+    }
+
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Option<()>) -> Option<()> {
+        if self.depth >= MAX_DEPTH {
+            return None;
+        }
+
+        self.depth += 1;
+        let parsed = parse(self);
+        self.depth -= 1;
+
+        parsed
+    }
+
+    /// Parses a Rust struct printed with `Debug`, such as `Request { id: 42, path: "/x" }`.
+    fn rust_struct(&mut self) -> Option<()> {
+        let len = path_length(self.rest())?;
+        self.push(TokenKind::TypeName, len);
+        self.whitespace();
+        self.rust_fields()
+    }
+
+    fn rust_fields(&mut self) -> Option<()> {
+        self.eat(TokenKind::Bracket, "{")?;
+        self.rust_elements("}", Self::rust_field)
+    }
+
+    /// Parses comma-separated elements up to and including `close`, allowing a trailing comma.
+    fn rust_elements(&mut self, close: &str, element: fn(&mut Self) -> Option<()>) -> Option<()> {
+        loop {
+            self.whitespace();
+            if self.eat(TokenKind::Bracket, close).is_some() {
+                return Some(());
+            }
+
+            element(self)?;
+            self.whitespace();
+
+            if self.eat(TokenKind::Comma, ",").is_none() {
+                return self.eat(TokenKind::Bracket, close);
+            }
+        }
+    }
+
+    fn rust_field(&mut self) -> Option<()> {
+        if self.eat(TokenKind::Plain, "..").is_some() {
+            return Some(());
+        }
+
+        let len = identifier_length(self.rest())?;
+        self.push(TokenKind::Field, len);
+        self.eat(TokenKind::Separator, ":")?;
+        self.whitespace();
+        self.rust_value()
+    }
+
+    /// Parses an entry of a map (`"key": value`) or a set (`value`).
+    fn rust_entry(&mut self) -> Option<()> {
+        self.rust_value()?;
+        self.whitespace();
+
+        match self.eat(TokenKind::Separator, ":") {
+            Some(()) => {
+                self.whitespace();
+                self.rust_value()
+            }
+            None => Some(()),
+        }
+    }
+
+    fn rust_value(&mut self) -> Option<()> {
+        self.nested(|parser| {
+            let rest = parser.rest();
+
+            match rest.chars().next()? {
+                '"' | '\'' => parser.push(TokenKind::String, string_length(rest)?),
+                '-' | '0'..='9' => parser.push(TokenKind::Number, number_length(rest)?),
+                '[' => {
+                    parser.push(TokenKind::Bracket, 1);
+                    return parser.rust_elements("]", Self::rust_value);
+                }
+                '(' => {
+                    parser.push(TokenKind::Bracket, 1);
+                    return parser.rust_elements(")", Self::rust_value);
+                }
+                '{' => {
+                    parser.push(TokenKind::Bracket, 1);
+                    return parser.rust_elements("}", Self::rust_entry);
+                }
+                '.' => return parser.eat(TokenKind::Plain, ".."),
+                _ => {
+                    let len = path_length(rest)?;
+                    let kind = match &rest[..len] {
+                        "true" | "false" => TokenKind::Boolean,
+                        "None" => TokenKind::Null,
+                        _ => TokenKind::TypeName,
+                    };
+                    parser.push(kind, len);
+
+                    // Structs, tuple structs and enum variants with data
+                    if kind == TokenKind::TypeName {
+                        if parser.rest().trim_start_matches(' ').starts_with('{') {
+                            parser.whitespace();
+                            return parser.rust_fields();
+                        }
+                        if parser.eat(TokenKind::Bracket, "(").is_some() {
+                            return parser.rust_elements(")", Self::rust_value);
+                        }
+                    }
+                }
+            }
+
+            Some(())
+        })
+    }
+
+    /// Parses a Go struct printed with `%+v`, such as `&{ID:42 Path:/x}`.
+    fn go_struct(&mut self) -> Option<()> {
+        self.nested(|parser| {
+            let _ = parser.eat(TokenKind::Bracket, "&");
+            parser.eat(TokenKind::Bracket, "{")?;
+
+            loop {
+                if parser.eat(TokenKind::Bracket, "}").is_some() {
+                    return Some(());
+                }
+
+                let len = identifier_length(parser.rest())?;
+                parser.push(TokenKind::Field, len);
+                parser.eat(TokenKind::Separator, ":")?;
+                parser.go_value(false)?;
+
+                if parser.eat(TokenKind::Bracket, "}").is_some() {
+                    return Some(());
+                }
+                parser.eat(TokenKind::Plain, " ")?;
+            }
+        })
+    }
+
+    /// Parses a Go value. Values in slices and maps end at the next space, while field values may contain spaces.
+    fn go_value(&mut self, in_list: bool) -> Option<()> {
+        let rest = self.rest();
+
+        if rest.starts_with("&{") || rest.starts_with('{') {
+            return self.go_struct();
+        }
+
+        if rest.starts_with("map[") {
+            self.push(TokenKind::TypeName, 3);
+            return self.nested(|parser| parser.go_elements(Self::go_map_entry));
+        }
+
+        if rest.starts_with('[') {
+            return self.nested(|parser| parser.go_elements(|parser| parser.go_value(true)));
+        }
+
+        let len = go_bare_length(rest, in_list);
+        if len > 0 {
+            self.push(go_bare_kind(&rest[..len]), len);
+        }
+
+        Some(())
+    }
+
+    /// Parses space-separated elements enclosed in square brackets.
+    fn go_elements(&mut self, element: fn(&mut Self) -> Option<()>) -> Option<()> {
+        self.eat(TokenKind::Bracket, "[")?;
+
+        loop {
+            if self.eat(TokenKind::Bracket, "]").is_some() {
+                return Some(());
+            }
+
+            element(self)?;
+
+            if self.eat(TokenKind::Bracket, "]").is_some() {
+                return Some(());
+            }
+            self.eat(TokenKind::Plain, " ")?;
+        }
+    }
+
+    fn go_map_entry(&mut self) -> Option<()> {
+        let rest = self.rest();
+        let len = rest.find([':', ' ', ']']).filter(|&end| rest[end..].starts_with(':'))?;
+
+        if len > 0 {
+            self.push(go_bare_kind(&rest[..len]), len);
+        }
+        self.eat(TokenKind::Separator, ":")?;
+        self.go_value(true)
+    }
+}
+
+impl StructHighlighter {
+    pub fn new(config: StructConfig) -> Self {
+        Self {
+            type_name: config.type_name.into(),
+            field: config.field.into(),
+            separator: config.separator.into(),
+            bracket: config.bracket.into(),
+            comma: config.comma.into(),
+            quote_token: config.quote_token.into(),
+            string: config.string.into(),
+            number: config.number.into(),
+            boolean: config.boolean.into(),
+            null: config.null.into(),
+        }
+    }
+
+    fn paint_tokens(&self, tokens: &[Token<'_>], output: &mut String) {
+        for token in tokens {
+            let style = match token.kind {
+                TokenKind::TypeName => self.type_name,
+                TokenKind::Field => self.field,
+                TokenKind::Separator => self.separator,
+                TokenKind::Bracket => self.bracket,
+                TokenKind::Comma => self.comma,
+                TokenKind::Number => self.number,
+                TokenKind::Boolean => self.boolean,
+                TokenKind::Null => self.null,
+                TokenKind::String if token.text.starts_with(['"', '\'']) => {
+                    let quote = &token.text[..1];
+                    let content = &token.text[1..token.text.len() - 1];
+                    write!(
+                        output,
+                        "{}{}{}",
+                        self.quote_token.paint(quote),
+                        self.string.paint(content),
+                        self.quote_token.paint(quote)
+                    )
+                    .unwrap();
+                    continue;
+                }
+                TokenKind::String => self.string,
+                TokenKind::Plain => {
+                    output.push_str(token.text);
+                    continue;
+                }
+            };
+
+            write!(output, "{}", style.paint(token.text)).unwrap();
+        }
+    }
+}
+
+impl Highlight for StructHighlighter {
+    fn apply(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        let mut last_end = 0;
+        let mut pos = 0;
+
+        while let Some(ch) = input[pos..].chars().next() {
+            if let Some(parser) = parse_struct_at(input, pos) {
+                output.push_str(&input[last_end..pos]);
+                self.paint_tokens(&parser.tokens, &mut output);
+                last_end = parser.pos;
+                pos = parser.pos;
+                continue;
+            }
+
+            pos += ch.len_utf8();
+        }
+
+        output.push_str(&input[last_end..]);
+
+        output
+    }
+}
+
+/// Parses the struct dump starting at `start`, if any.
+///
+/// Rust structs start with a capitalized type name and Go structs with `&{` or `{` directly followed by a field name.
+fn parse_struct_at(input: &str, start: usize) -> Option<Parser<'_>> {
+    let glued_to_word = input[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '.' | '&'));
+    if glued_to_word {
+        return None;
+    }
+
+    let rest = &input[start..];
+    let mut parser = Parser::new(input, start);
+    let parsed = match rest.chars().next()? {
+        '&' | '{' if is_go_struct_start(rest) => parser.go_struct(),
+        c if c.is_ascii_uppercase() => parser.rust_struct(),
+        _ => None,
+    };
+
+    parsed.map(|()| parser)
+}
+
+fn is_go_struct_start(rest: &str) -> bool {
+    let fields = rest.strip_prefix('&').unwrap_or(rest);
+
+    fields
+        .strip_prefix('{')
+        .and_then(|fields| identifier_length(fields).map(|len| fields[len..].starts_with(':')))
+        .unwrap_or(false)
+}
+
+fn identifier_length(input: &str) -> Option<usize> {
+    if !input.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        return None;
+    }
+
+    Some(
+        input
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(input.len()),
+    )
+}
+
+/// Returns the length of a path such as `Duration` or `std::time::Duration`.
+fn path_length(input: &str) -> Option<usize> {
+    let mut len = identifier_length(input)?;
+
+    while let Some(segment) = input[len..].strip_prefix("::") {
+        len += 2 + identifier_length(segment)?;
+    }
+
+    Some(len)
+}
+
+/// Returns the length of the quoted string or character at the start of `input`, including both quotes.
+fn string_length(input: &str) -> Option<usize> {
+    let quote = input.chars().next()?;
+    let mut escaped = false;
+
+    for (i, ch) in input.char_indices().skip(1) {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return Some(i + 1),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns the length of a number such as `-42`, `1.5e-7` or `0xff`.
+fn number_length(input: &str) -> Option<usize> {
+    let digits_start = usize::from(input.starts_with('-'));
+    if !input[digits_start..].starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut len = digits_start;
+    let mut previous = '-';
+
+    for ch in input[digits_start..].chars() {
+        let is_sign_of_exponent = matches!(ch, '-' | '+') && matches!(previous, 'e' | 'E');
+        if !(ch.is_ascii_alphanumeric() || ch == '.' || ch == '_' || is_sign_of_exponent) {
+            break;
+        }
+        len += 1;
+        previous = ch;
+    }
+
+    Some(len)
+}
+
+/// Returns the length of an unquoted Go value.
+///
+/// Values end at a closing bracket. Values in slices and maps also end at the next space, while field values only end
+/// at a space followed by the name of the next field.
+fn go_bare_length(input: &str, in_list: bool) -> usize {
+    for (i, ch) in input.char_indices() {
+        let ends_value = match ch {
+            '}' | ']' => true,
+            ' ' => in_list || is_go_field(&input[i + 1..]),
+            _ => false,
+        };
+
+        if ends_value {
+            return i;
+        }
+    }
+
+    input.len()
+}
+
+fn is_go_field(input: &str) -> bool {
+    identifier_length(input).is_some_and(|len| input[len..].starts_with(':'))
+}
+
+fn go_bare_kind(text: &str) -> TokenKind {
+    match text {
+        "true" | "false" => TokenKind::Boolean,
+        "<nil>" | "nil" => TokenKind::Null,
+        _ if number_length(text) == Some(text.len()) => TokenKind::Number,
+        _ => TokenKind::String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_struct_highlighter() {
+        let highlighter = StructHighlighter::new(StructConfig {
+            type_name: Style::new().fg(Color::Blue),
+            field: Style::new().fg(Color::Yellow),
+            separator: Style::new(),
+            bracket: Style::new(),
+            comma: Style::new(),
+            quote_token: Style::new(),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Cyan),
+            boolean: Style::new().fg(Color::Magenta),
+            null: Style::new().fg(Color::Red),
+        });
+
+        let cases = vec![
+            (
+                r#"got Request { id: 42, path: "/x", headers: {..}, user: None, active: Some(true) }"#,
+                r#"got [blue]Request[reset] { [yellow]id[reset]: [cyan]42[reset], [yellow]path[reset]: "[green]/x[reset]", [yellow]headers[reset]: {..}, [yellow]user[reset]: [red]None[reset], [yellow]active[reset]: [blue]Some[reset]([magenta]true[reset]) }"#,
+            ),
+            (
+                r#"Config { ratio: -1.5e-3, tags: ["a", 'b'], inner: Inner { x: 1, .. } }"#,
+                r#"[blue]Config[reset] { [yellow]ratio[reset]: [cyan]-1.5e-3[reset], [yellow]tags[reset]: ["[green]a[reset]", '[green]b[reset]'], [yellow]inner[reset]: [blue]Inner[reset] { [yellow]x[reset]: [cyan]1[reset], .. } }"#,
+            ),
+            (
+                "req=&{ID:42 Path:/x Tags:[a b] Inner:{Err:<nil>}}",
+                "req=&{[yellow]ID[reset]:[cyan]42[reset] [yellow]Path[reset]:[green]/x[reset] [yellow]Tags[reset]:[[green]a[reset] [green]b[reset]] [yellow]Inner[reset]:{[yellow]Err[reset]:[red]<nil>[reset]}}",
+            ),
+            (
+                "{Msg:hello world Labels:map[env:prod] Ok:true}",
+                "{[yellow]Msg[reset]:[green]hello world[reset] [yellow]Labels[reset]:[blue]map[reset][[green]env[reset]:[green]prod[reset]] [yellow]Ok[reset]:[magenta]true[reset]}",
+            ),
+            (
+                "Failed to parse {config} for User {name}",
+                "Failed to parse {config} for User {name}",
+            ),
+            ("No structs here!", "No structs here!"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use nu_ansi_term::Style as NuStyle;
+use regex::{Captures, Error, Regex};
+
+use crate::highlighter::Highlight;
+use crate::patterns::{alternation, glued_after, keyed_value_regex};
+use crate::scale::style_for;
+use crate::{ThresholdConfig, ThresholdScale};
+
+pub struct ThresholdHighlighter {
+    regex: Regex,
+    units: Vec<(String, f64)>,
+    scale: ThresholdScale,
+}
+
+impl ThresholdHighlighter {
+    /// Creates a highlighter for the values of `config.keys`, such as `latency=1200ms`.
+    pub fn from_keys(config: &ThresholdConfig) -> Result<Self, Error> {
+        let units = alternation(config.units.iter().map(|(unit, _)| unit));
+
+        let regex = keyed_value_regex(&config.keys, &format!(r"-?\d+(?:\.\d+)?(?:\ ?(?:{units}))?"))?;
+
+        Ok(Self::new(regex, config))
+    }
+
+    /// Creates a highlighter for the `value` capture group of `regex`.
+    pub fn from_regex(regex: &str, config: &ThresholdConfig) -> Result<Self, Error> {
+        Ok(Self::new(Regex::new(regex)?, config))
+    }
+
+    fn new(regex: Regex, config: &ThresholdConfig) -> Self {
+        Self {
+            regex,
+            units: config.units.clone(),
+            scale: config.scale.clone(),
+        }
+    }
+
+    /// Parses a number with an optional unit, such as `1200ms` or `1.5 s`, and normalizes it with the unit factors.
+    fn normalize(&self, value: &str) -> Option<f64> {
+        let number_length = value
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+            .unwrap_or(value.len());
+        let number: f64 = value[..number_length].parse().ok()?;
+        let unit = value[number_length..].trim_start();
+
+        match unit.is_empty() {
+            true => Some(number),
+            false => self
+                .units
+                .iter()
+                .find(|(name, _)| name == unit)
+                .map(|(_, factor)| number * factor),
+        }
+    }
+}
+
+impl Highlight for ThresholdHighlighter {
+    fn apply(&self, input: &str) -> String {
+        self.regex
+            .replace_all(input, |caps: &Captures<'_>| {
+                let matched = caps.get(0).unwrap();
+                let Some(value) = caps.name("value") else {
+                    return matched.as_str().to_string();
+                };
+
+                // A unit after a space may be the start of the next word, such as `ms` in `latency=20 msg=hello`
+                let mut end = value.end();
+                if glued_after(input, end)
+                    && let Some(space) = value.as_str().find(' ')
+                {
+                    end = value.start() + space;
+                }
+
+                // Values glued to more text, like `12seconds`, are not numbers with a known unit
+                let style = match glued_after(input, end) {
+                    true => None,
+                    false => self
+                        .normalize(&input[value.start()..end])
+                        .and_then(|number| style_for(&self.scale, number)),
+                };
+
+                match style {
+                    Some(style) => format!(
+                        "{}{}{}",
+                        &input[matched.start()..value.start()],
+                        NuStyle::from(style).paint(&input[value.start()..end]),
+                        &input[end..matched.end()]
+                    ),
+                    None => matched.as_str().to_string(),
+                }
+            })
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_threshold_steps() {
+        let highlighter = ThresholdHighlighter::from_keys(&ThresholdConfig {
+            keys: vec!["latency".to_string(), "duration".to_string()],
+            ..ThresholdConfig::default()
+        })
+        .unwrap();
+
+        let cases = vec![
+            ("latency=1200ms", "latency=[red]1200ms[reset]"),
+            ("latency=20ms", "latency=[green]20ms[reset]"),
+            ("duration: 0.5 s", "duration: [yellow]0.5 s[reset]"),
+            (r#"{"latency": 1.5}"#, r#"{"latency": [green]1.5[reset]}"#),
+            ("latency=3seconds retries=1200", "latency=3seconds retries=1200"),
+            ("latency=20 msg=hello", "latency=[green]20[reset] msg=hello"),
+            ("duration=5 status=ok", "duration=[green]5[reset] status=ok"),
+            ("latency=5h", "latency=5h"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+
+    #[test]
+    fn test_threshold_gradient_with_regex_context() {
+        let highlighter = ThresholdHighlighter::from_regex(
+            r"took (?P<value>\S+)",
+            &ThresholdConfig {
+                scale: ThresholdScale::Gradient {
+                    low: 0.0,
+                    high: 1000.0,
+                    from: (0, 255, 0),
+                    to: (255, 0, 0),
+                },
+                ..ThresholdConfig::default()
+            },
+        )
+        .unwrap();
+
+        let cases = vec![
+            ("took 500ms", "took \x1b[38;2;128;128;0m500ms\x1b[0m"),
+            ("took 2s", "took \x1b[38;2;255;0;0m2s\x1b[0m"),
+            ("took forever at 500ms", "took forever at 500ms"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(expected, highlighter.apply(input));
+        }
+    }
+}
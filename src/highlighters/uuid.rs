@@ -2,13 +2,15 @@ use nu_ansi_term::Style as NuStyle;
 use regex::{Captures, Error, Regex};
 
 use crate::highlighter::Highlight;
-use crate::UuidConfig;
+use crate::palette;
+use crate::{Style, UuidConfig};
 
 pub struct UuidHighlighter {
     regex: Regex,
     number: NuStyle,
     letter: NuStyle,
     dash: NuStyle,
+    palette: Vec<Style>,
 }
 
 impl UuidHighlighter {
@@ -32,6 +34,7 @@ impl UuidHighlighter {
             number: config.number.into(),
             letter: config.letter.into(),
             dash: config.dash.into(),
+            palette: config.palette,
         })
     }
 }
@@ -40,6 +43,10 @@ impl Highlight for UuidHighlighter {
     fn apply(&self, input: &str) -> String {
         self.regex
             .replace_all(input, |caps: &Captures<'_>| {
+                if let Some(style) = palette::pick(&self.palette, &caps[0]) {
+                    return format!("{}", NuStyle::from(style).paint(&caps[0]));
+                }
+
                 caps[0]
                     .chars()
                     .map(|c| match c {
@@ -68,6 +75,7 @@ mod tests {
             number: Style::new().fg(Color::Cyan),
             letter: Style::new().fg(Color::Yellow),
             dash: Style::new().fg(Color::Red),
+            palette: Vec::new(),
         })
         .unwrap();
 
@@ -91,4 +99,20 @@ mod tests {
             assert_eq!(expected, actual.convert_escape_codes());
         }
     }
+
+    #[test]
+    fn test_uuid_highlighter_with_palette() {
+        let highlighter = UuidHighlighter::new(UuidConfig {
+            palette: vec![Style::new().fg(Color::Red), Style::new().fg(Color::Green)],
+            ..UuidConfig::default()
+        })
+        .unwrap();
+
+        let input = "start 550e8400-e29b-41d4-a716-446655440000 end 550e8400-e29b-41d4-a716-446655440000 and 123e4567-e89b-12d3-a456-426614174000";
+        let expected = "start [red]550e8400-e29b-41d4-a716-446655440000[reset] end [red]550e8400-e29b-41d4-a716-446655440000[reset] and [green]123e4567-e89b-12d3-a456-426614174000[reset]";
+
+        let actual = highlighter.apply(input);
+
+        assert_eq!(expected, actual.convert_escape_codes());
+    }
 }
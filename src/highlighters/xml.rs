@@ -0,0 +1,247 @@
+use crate::highlighter::Highlight;
+use crate::XmlConfig;
+use nu_ansi_term::Style as NuStyle;
+use std::fmt::Write;
+
+const COMMENT_START: &str = "<!--";
+const COMMENT_END: &str = "-->";
+const CDATA_START: &str = "<![CDATA[";
+const CDATA_END: &str = "]]>";
+
+pub struct XmlHighlighter {
+    tag: NuStyle,
+    attribute: NuStyle,
+    equals: NuStyle,
+    attribute_value: NuStyle,
+    angle_bracket: NuStyle,
+    cdata: NuStyle,
+    comment: NuStyle,
+}
+
+impl XmlHighlighter {
+    pub fn new(config: XmlConfig) -> Self {
+        Self {
+            tag: config.tag.into(),
+            attribute: config.attribute.into(),
+            equals: config.equals.into(),
+            attribute_value: config.attribute_value.into(),
+            angle_bracket: config.angle_bracket.into(),
+            cdata: config.cdata.into(),
+            comment: config.comment.into(),
+        }
+    }
+
+    /// Paints the markup at the start of `rest`, which starts with `<`, and returns the number of bytes consumed.
+    ///
+    /// Markup that is cut off by the end of the input is painted up to the end, but only tags with a namespace prefix or
+    /// an attribute value, which prose doesn't have. Returns `None` without writing anything if `rest` does not start
+    /// with a tag, comment or CDATA section.
+    fn paint_markup(&self, rest: &str, glued_to_word: bool, output: &mut String) -> Option<usize> {
+        if rest.starts_with(COMMENT_START) {
+            let len = section_length(rest, COMMENT_START, COMMENT_END);
+            write!(output, "{}", self.comment.paint(&rest[..len])).unwrap();
+            return Some(len);
+        }
+
+        if rest.starts_with(CDATA_START) {
+            let len = section_length(rest, CDATA_START, CDATA_END);
+            write!(output, "{}", self.cdata.paint(&rest[..len])).unwrap();
+            return Some(len);
+        }
+
+        let open = ["</", "<?", "<!", "<"]
+            .into_iter()
+            .find(|open| rest.starts_with(open))?;
+
+        // Opening tags glued to a word are more likely generics, such as `Vec<String>`
+        if open == "<" && glued_to_word {
+            return None;
+        }
+
+        let name_length = name_length(&rest[open.len()..])?;
+        let mut painted = String::new();
+        let mut pos = open.len() + name_length;
+        let mut looks_like_markup = rest[open.len()..pos].contains(':');
+
+        write!(
+            painted,
+            "{}{}",
+            self.angle_bracket.paint(open),
+            self.tag.paint(&rest[open.len()..pos])
+        )
+        .unwrap();
+
+        loop {
+            let whitespace = whitespace_length(&rest[pos..]);
+            painted.push_str(&rest[pos..pos + whitespace]);
+            pos += whitespace;
+
+            let remaining = &rest[pos..];
+            if remaining.is_empty() {
+                // Prose such as `count <max and done` is cut off just the same
+                match looks_like_markup {
+                    true => break,
+                    false => return None,
+                }
+            }
+
+            if let Some(close) = ["/>", "?>", ">"].into_iter().find(|close| remaining.starts_with(close)) {
+                write!(painted, "{}", self.angle_bracket.paint(close)).unwrap();
+                pos += close.len();
+                break;
+            }
+
+            // Attributes must be separated from the tag name and from each other by whitespace
+            if whitespace == 0 {
+                return None;
+            }
+
+            let attribute_length = self.paint_attribute(remaining, &mut painted)?;
+            looks_like_markup |= remaining[..attribute_length].contains('=');
+            pos += attribute_length;
+        }
+
+        output.push_str(&painted);
+
+        Some(pos)
+    }
+
+    /// Paints an attribute with an optional value and returns the number of bytes consumed.
+    fn paint_attribute(&self, rest: &str, output: &mut String) -> Option<usize> {
+        let mut pos = name_length(rest)?;
+        write!(output, "{}", self.attribute.paint(&rest[..pos])).unwrap();
+
+        let whitespace = whitespace_length(&rest[pos..]);
+        if !rest[pos + whitespace..].starts_with('=') {
+            // HTML allows attributes without a value, such as `<input disabled>`
+            return Some(pos);
+        }
+
+        output.push_str(&rest[pos..pos + whitespace]);
+        pos += whitespace;
+        write!(output, "{}", self.equals.paint("=")).unwrap();
+        pos += 1;
+
+        let whitespace = whitespace_length(&rest[pos..]);
+        output.push_str(&rest[pos..pos + whitespace]);
+        pos += whitespace;
+
+        let value = &rest[pos..];
+        let value_length = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].find(quote).map_or(value.len(), |end| end + 2),
+            _ => value
+                .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                .unwrap_or(value.len()),
+        };
+        write!(output, "{}", self.attribute_value.paint(&value[..value_length])).unwrap();
+
+        Some(pos + value_length)
+    }
+}
+
+impl Highlight for XmlHighlighter {
+    fn apply(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        let mut last_end = 0;
+        let mut search_from = 0;
+
+        while let Some(offset) = input[search_from..].find('<') {
+            let start = search_from + offset;
+            search_from = start + 1;
+
+            let glued_to_word = input[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+            let mut painted = String::new();
+            if let Some(len) = self.paint_markup(&input[start..], glued_to_word, &mut painted) {
+                output.push_str(&input[last_end..start]);
+                output.push_str(&painted);
+                last_end = start + len;
+                search_from = last_end;
+            }
+        }
+
+        output.push_str(&input[last_end..]);
+
+        output
+    }
+}
+
+/// Returns the length of a comment or CDATA section, or the length of the input if the section is cut off.
+fn section_length(input: &str, start: &str, end: &str) -> usize {
+    input[start.len()..]
+        .find(end)
+        .map_or(input.len(), |i| start.len() + i + end.len())
+}
+
+/// Returns the length of the tag or attribute name at the start of `input`, such as `soap:Envelope` or `data-id`.
+fn name_length(input: &str) -> Option<usize> {
+    if !input.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        return None;
+    }
+
+    Some(
+        input
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | ':' | '.' | '-')))
+            .unwrap_or(input.len()),
+    )
+}
+
+fn whitespace_length(input: &str) -> usize {
+    input.find(|c: char| !c.is_whitespace()).unwrap_or(input.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::highlighter::Highlight;
+    use crate::style::*;
+    use crate::tests::escape_code_converter::ConvertEscapeCodes;
+
+    use super::*;
+
+    #[test]
+    fn test_xml_highlighter() {
+        let highlighter = XmlHighlighter::new(XmlConfig {
+            tag: Style::new().fg(Color::Blue),
+            attribute: Style::new().fg(Color::Yellow),
+            equals: Style::new(),
+            attribute_value: Style::new().fg(Color::Green),
+            angle_bracket: Style::new().fg(Color::Red),
+            cdata: Style::new().fg(Color::Cyan),
+            comment: Style::new().fg(Color::Magenta),
+        });
+
+        let cases = vec![
+            (
+                r#"<m:Price currency="EUR">42</m:Price>"#,
+                r#"[red]<[reset][blue]m:Price[reset] [yellow]currency[reset]=[green]"EUR"[reset][red]>[reset]42[red]</[reset][blue]m:Price[reset][red]>[reset]"#,
+            ),
+            (
+                "<?xml version='1.0'?><br/><input disabled>",
+                "[red]<?[reset][blue]xml[reset] [yellow]version[reset]=[green]'1.0'[reset][red]?>[reset][red]<[reset][blue]br[reset][red]/>[reset][red]<[reset][blue]input[reset] [yellow]disabled[reset][red]>[reset]",
+            ),
+            (
+                "<a><!-- note --><![CDATA[x < y]]></a>",
+                "[red]<[reset][blue]a[reset][red]>[reset][magenta]<!-- note -->[reset][cyan]<![CDATA[x < y]]>[reset][red]</[reset][blue]a[reset][red]>[reset]",
+            ),
+            (
+                r#"payload=<order id="7"><item sku="A1"#,
+                r#"payload=[red]<[reset][blue]order[reset] [yellow]id[reset]=[green]"7"[reset][red]>[reset][red]<[reset][blue]item[reset] [yellow]sku[reset]=[green]"A1[reset]"#,
+            ),
+            ("<a><!-- cut off", "[red]<[reset][blue]a[reset][red]>[reset][magenta]<!-- cut off[reset]"),
+            (
+                "if a < b and Vec<String> is <3",
+                "if a < b and Vec<String> is <3",
+            ),
+            ("retry if count <max and done", "retry if count <max and done"),
+            ("cut off <soap:Body", "cut off [red]<[reset][blue]soap:Body[reset]"),
+        ];
+
+        for (input, expected) in cases {
+            let actual = highlighter.apply(input);
+            assert_eq!(expected, actual.convert_escape_codes());
+        }
+    }
+}
@@ -1,15 +1,20 @@
 pub use crate::config::*;
 pub use crate::error::Error;
 pub use crate::highlighter::Highlighter;
+pub use crate::highlighter::LineStream;
 pub use crate::style::Color;
 pub use crate::style::Style;
 
+mod ansi;
 pub mod config;
 pub mod defaults;
 mod error;
 pub mod highlighter;
 mod highlighters;
 mod normalizer;
+mod palette;
+mod patterns;
+mod scale;
 mod split_and_apply;
 pub mod style;
 
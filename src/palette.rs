@@ -0,0 +1,39 @@
+use crate::Style;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Picks a style from `palette` by a hash of `text`, so that the same text always gets the same style.
+///
+/// Uses FNV-1a, which unlike the hasher of the standard library is stable across runs, versions and platforms.
+pub fn pick(palette: &[Style], text: &str) -> Option<Style> {
+    if palette.is_empty() {
+        return None;
+    }
+
+    let hash = text.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+
+    Some(palette[(hash % palette.len() as u64) as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn test_pick_is_stable() {
+        let palette = [
+            Style::new().fg(Color::Red),
+            Style::new().fg(Color::Green),
+            Style::new().fg(Color::Blue),
+        ];
+
+        assert_eq!(pick(&palette, "req-42"), pick(&palette, "req-42"));
+        assert_eq!(pick(&palette, "thread-1"), Some(palette[2]));
+        assert_eq!(pick(&palette, "thread-2"), Some(palette[0]));
+        assert_eq!(pick(&[], "a"), None);
+    }
+}
@@ -0,0 +1,50 @@
+use regex::{Error, Regex};
+
+/// A number with an optional fraction, such as `42` or `1.5`.
+pub const NUMBER: &str = r"\d+(?:\.\d+)?";
+
+/// Joins `names` into an alternation that never matches if there are no names.
+///
+/// Longer names come first, so that `ms` is preferred over `m`.
+pub fn alternation<'a>(names: impl Iterator<Item = &'a String>) -> String {
+    let mut names: Vec<&String> = names.collect();
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+    match names.is_empty() {
+        true => r"[^\s\S]".to_string(),
+        false => names
+            .iter()
+            .map(|name| regex::escape(name))
+            .collect::<Vec<_>>()
+            .join("|"),
+    }
+}
+
+/// Returns a pattern for one or more quantities without spaces, such as `2h30m`, with units from the `units`
+/// alternation.
+pub fn glued_quantities(units: &str) -> String {
+    format!(r"(?:{NUMBER}(?:{units}))+")
+}
+
+/// Returns true if the text at `end` continues the word before it, like the `ph` after `5m` in `5mph`.
+pub fn glued_after(input: &str, end: usize) -> bool {
+    input[end..]
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Builds a regex for values matching `value` after one of `keys`, such as `latency=1200ms` or `"latency": 1200`. The
+/// value is captured in the `value` group.
+pub fn keyed_value_regex(keys: &[String], value: &str) -> Result<Regex, Error> {
+    let keys = alternation(keys.iter());
+
+    Regex::new(&format!(
+        r#"(?x)
+        (?:^|[\s,;{{(\[])                   # The key starts a word
+        ["']?(?:{keys})["']?                # The key, optionally quoted
+        \s*[=:]\s*["']?
+        (?P<value>{value})
+        "#
+    ))
+}
@@ -0,0 +1,26 @@
+use crate::{Color, Style, ThresholdScale};
+
+/// Returns the style of `value` on `scale`, or `None` if it is below the first step.
+pub fn style_for(scale: &ThresholdScale, value: f64) -> Option<Style> {
+    match scale {
+        ThresholdScale::Steps(steps) => steps
+            .iter()
+            .take_while(|(threshold, _)| value >= *threshold)
+            .last()
+            .map(|(_, style)| *style),
+        ThresholdScale::Gradient { low, high, from, to } => {
+            let position = match high > low {
+                true => ((value - low) / (high - low)).clamp(0.0, 1.0),
+                false => 1.0,
+            };
+            let blend =
+                |from: u8, to: u8| (f64::from(from) + (f64::from(to) - f64::from(from)) * position).round() as u8;
+
+            Some(Style::new().fg(Color::Rgb(
+                blend(from.0, to.0),
+                blend(from.1, to.1),
+                blend(from.2, to.2),
+            )))
+        }
+    }
+}
@@ -36,7 +36,7 @@ enum Chunk<'a> {
     AlreadyHighlighted(&'a str),
 }
 
-fn split_into_chunks(input: &str) -> Vec<Chunk> {
+fn split_into_chunks(input: &str) -> Vec<Chunk<'_>> {
     let reset_code = "\x1b[0m";
     let escape_code = "\x1b[";
 
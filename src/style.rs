@@ -71,6 +71,7 @@ pub enum Color {
     BrightMagenta,
     BrightCyan,
     BrightWhite,
+    Rgb(u8, u8, u8),
 }
 
 impl From<&Color> for NuColor {
@@ -93,6 +94,7 @@ impl From<&Color> for NuColor {
             Color::BrightMagenta => NuColor::LightMagenta,
             Color::BrightCyan => NuColor::LightCyan,
             Color::BrightWhite => NuColor::LightGray,
+            Color::Rgb(r, g, b) => NuColor::Rgb(*r, *g, *b),
         }
     }
 }
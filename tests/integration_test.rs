@@ -17,6 +17,7 @@ fn it_works() {
                 fg: Some(Color::Cyan),
                 ..Style::default()
             },
+            ..NumberConfig::default()
         })
         .with_quote_highlighter(QuotesConfig {
             quotes_token: '"',
@@ -24,6 +25,7 @@ fn it_works() {
                 fg: Some(Color::Yellow),
                 ..Style::default()
             },
+            inner: None,
         })
         .with_uuid_highlighter(UuidConfig::default());
 
@@ -37,3 +39,87 @@ fn it_works() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn line_highlighter_styles_gaps_between_tokens() {
+    let mut builder = Highlighter::builder();
+
+    builder
+        .with_number_highlighter(NumberConfig {
+            style: Style::new().fg(Color::Cyan),
+            ..NumberConfig::default()
+        })
+        .with_line_keyword_highlighter(KeywordConfig {
+            words: vec!["ERROR".to_string()],
+            style: Style::new().on(Color::Red),
+        });
+
+    let highlighter = builder.build().expect("Failed to build highlighter");
+
+    let actual = highlighter.apply("ERROR 42 failed");
+    let expected = "\u{1b}[41mERROR \u{1b}[36m42\u{1b}[0m\u{1b}[41m failed\u{1b}[0m".to_string();
+    assert_eq!(actual, expected);
+
+    let actual = highlighter.apply("INFO 42 done");
+    let expected = "INFO \u{1b}[36m42\u{1b}[0m done".to_string();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn stream_highlights_json_spread_over_several_lines() {
+    let mut builder = Highlighter::builder();
+
+    builder.with_json_highlighter(JsonConfig {
+        key: Style::new().fg(Color::Yellow),
+        ..JsonConfig::default()
+    });
+
+    let highlighter = builder.build().expect("Failed to build highlighter");
+    let lines = ["{", "  \"id\": 42", "}"];
+
+    let single_lines: Vec<String> = lines.iter().map(|line| highlighter.apply(line)).collect();
+    assert_eq!(single_lines[1], "  \"id\": 42");
+
+    let mut stream = highlighter.stream();
+    let streamed: Vec<String> = lines.iter().map(|line| stream.apply(line)).collect();
+    assert!(streamed[1].contains("\u{1b}[33mid\u{1b}[0m"));
+}
+
+#[test]
+fn stream_starts_over_when_an_open_json_document_does_not_continue() {
+    let mut builder = Highlighter::builder();
+
+    builder.with_json_highlighter(JsonConfig {
+        key: Style::new().fg(Color::Yellow),
+        ..JsonConfig::default()
+    });
+
+    let highlighter = builder.build().expect("Failed to build highlighter");
+    let lines = ["fn main() {", "INFO payload = {", "  \"id\": 42", "}"];
+
+    let mut stream = highlighter.stream();
+    let streamed: Vec<String> = lines.iter().map(|line| stream.apply(line)).collect();
+    assert!(streamed[1].starts_with("INFO payload = "));
+    assert_ne!(streamed[1], lines[1]);
+    assert!(streamed[2].contains("\u{1b}[33mid\u{1b}[0m"));
+}
+
+#[test]
+fn stream_annotates_lines_with_relative_time() {
+    let mut builder = Highlighter::builder();
+
+    builder.with_relative_time_annotations(RelativeTimeConfig {
+        scale: ThresholdScale::Steps(vec![(1.0, Style::new().fg(Color::Red))]),
+    });
+
+    let highlighter = builder.build().expect("Failed to build highlighter");
+    let lines = ["10:00:00.000 start", "10:00:00.153 step", "10:00:05.000 slow"];
+
+    assert_eq!(highlighter.apply(lines[1]), "10:00:00.153 step");
+
+    let mut stream = highlighter.stream();
+    let streamed: Vec<String> = lines.iter().map(|line| stream.apply(line)).collect();
+    assert_eq!(streamed[0], "10:00:00.000 start");
+    assert_eq!(streamed[1], "+0.153s 10:00:00.153 step");
+    assert_eq!(streamed[2], "\u{1b}[31m+4.847s\u{1b}[0m 10:00:05.000 slow");
+}